@@ -0,0 +1,55 @@
+use aoc::answers::Answers;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tools::input;
+use tools::runner::Part;
+use tools::Solution;
+
+/// Benchmarks parsing and both parts of a day on its `input.txt`.
+///
+/// Parts without an accepted answer for `input.txt` in `answers.toml` are skipped; they either
+/// aren't solved or don't finish in reasonable time.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let content = input::load(aoc::day_dir(S::DAY).join("input.txt")).expect("input.txt");
+    let parts = Answers::load(S::DAY)
+        .expect("answers.toml")
+        .get("input.txt")
+        .map(|expected| expected.parts())
+        .unwrap_or_default();
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&content))));
+
+    let root = S::parse(&content).unwrap_or_else(|e| panic!("day {} does not parse: {}", S::DAY, e));
+    if parts.contains(&Part::One) {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&root))));
+    }
+    if parts.contains(&Part::Two) {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&root))));
+    }
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<day01::Day01>,
+    bench_day::<day02::Day02>,
+    bench_day::<day03::Day03>,
+    bench_day::<day04::Day04>,
+    bench_day::<day05::Day05>,
+    bench_day::<day06::Day06>,
+    bench_day::<day07::Day07>,
+    bench_day::<day08::Day08>,
+    bench_day::<day09::Day09>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_day::<day14::Day14>,
+    bench_day::<day15::Day15>,
+    bench_day::<day16::Day16>,
+    bench_day::<day17::Day17>,
+    bench_day::<day18::Day18>,
+    bench_day::<day19::Day19>,
+);
+criterion_main!(days);
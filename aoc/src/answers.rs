@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use miette::{IntoDiagnostic, Result, WrapErr};
+use serde::Deserialize;
+use tools::runner::Part;
+
+use crate::{day_dir, input_name};
+
+/// Expected answers for one input file. A part that isn't listed isn't checked.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<String> {
+        let value = match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }?;
+
+        Some(match value {
+            toml::Value::String(answer) => answer.clone(),
+            answer => answer.to_string(),
+        })
+    }
+
+    /// Parts that have an expected answer.
+    pub fn parts(&self) -> Vec<Part> {
+        Part::all().into_iter().filter(|part| self.get(*part).is_some()).collect()
+    }
+}
+
+/// A day's `answers.toml`: expected answers keyed by the name of the input file next to it.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, Expected>);
+
+impl Answers {
+    pub fn load(day: u8) -> Result<Answers> {
+        let path = day_dir(day).join("answers.toml");
+        let content = fs::read_to_string(&path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Cannot read {}", path.display()))?;
+
+        toml::from_str(&content)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Invalid {}", path.display()))
+    }
+
+    pub fn get(&self, input: &str) -> Option<&Expected> {
+        self.0.get(input)
+    }
+
+    /// The answers for the input file at `path`, resolved as `aoc run` does, for a file listed next to this
+    /// `answers.toml`.
+    pub fn for_input(&self, day: u8, path: &Path) -> Option<&Expected> {
+        self.get(&input_name(day, path)?)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Expected)> {
+        self.0.iter()
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::{Path, PathBuf};
+
+use tools::runner::{Runnable, Runner};
+
+pub mod answers;
+
+/// Every solved day, in calendar order.
+pub fn days() -> Vec<Box<dyn Runnable>> {
+    vec![
+        Runner::<day01::Day01>::boxed(),
+        Runner::<day02::Day02>::boxed(),
+        Runner::<day03::Day03>::boxed(),
+        Runner::<day04::Day04>::boxed(),
+        Runner::<day05::Day05>::boxed(),
+        Runner::<day06::Day06>::boxed(),
+        Runner::<day07::Day07>::boxed(),
+        Runner::<day08::Day08>::boxed(),
+        Runner::<day09::Day09>::boxed(),
+        Runner::<day10::Day10>::boxed(),
+        Runner::<day11::Day11>::boxed(),
+        Runner::<day12::Day12>::boxed(),
+        Runner::<day13::Day13>::boxed(),
+        Runner::<day14::Day14>::boxed(),
+        Runner::<day15::Day15>::boxed(),
+        Runner::<day16::Day16>::boxed(),
+        Runner::<day17::Day17>::boxed(),
+        Runner::<day18::Day18>::boxed(),
+        Runner::<day19::Day19>::boxed(),
+    ]
+}
+
+/// Crate directory of a day, where its `input.txt` and samples live.
+pub fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day{:02}", day))
+}
+
+/// Relative inputs that don't exist from the current directory are looked up in the day's directory,
+/// so `--input sample.txt` works for any day.
+pub fn resolve_input(day: u8, input: &Path) -> PathBuf {
+    if input.is_absolute() || input.exists() {
+        input.to_path_buf()
+    } else {
+        day_dir(day).join(input)
+    }
+}
+
+/// The name `answers.toml` knows a resolved input by: its path inside the day's directory, whichever way it was
+/// written on the command line. `None` for files outside the day's directory.
+pub fn input_name(day: u8, path: &Path) -> Option<String> {
+    let dir = day_dir(day).canonicalize().ok()?;
+    let path = path.canonicalize().ok()?;
+    path.strip_prefix(dir).ok().map(|name| name.to_string_lossy().into_owned())
+}
@@ -0,0 +1,259 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use day01::explain;
+use day01::recognizer::{self, Recognizer};
+use miette::{miette, IntoDiagnostic, Result, WrapErr};
+use aoc::answers::Answers;
+use tools::{anim, input};
+use tools::runner::{Part, Runnable};
+
+#[derive(Debug, Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+        s.parse().map(Selection::Day).map_err(|_| format!("expected a day number or `all`, got `{}`", s))
+    }
+}
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or all of them, and print the answers with timings
+    Run {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        day: Selection,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, can be repeated; relative paths are also looked up in the day's directory [default: input.txt]
+        #[arg(short, long)]
+        input: Vec<PathBuf>,
+        /// Also draw the puzzle to out/dayNN.svg, for days that can
+        #[arg(long)]
+        svg: bool,
+        /// Also draw the puzzle to out/dayNN.ppm, for days that can
+        #[arg(long)]
+        ppm: bool,
+        /// Replay the simulation afterwards at this many frames per second, for days that record one
+        #[arg(long, value_name = "FPS")]
+        animate: Option<u32>,
+        /// Save the recorded simulation as numbered frames in this directory
+        #[arg(long, value_name = "DIR")]
+        frames: Option<PathBuf>,
+        /// The image format of the saved frames
+        #[arg(long, value_enum, default_value_t = FrameFormat::Ppm)]
+        frames_format: FrameFormat,
+        /// Also run parts without an accepted answer for the input in answers.toml, which may not finish
+        #[arg(long)]
+        unanswered: bool,
+    },
+    /// Show how day 1 reads the calibration value of every line
+    Explain {
+        /// Input file; relative paths are also looked up in day 1's directory
+        #[arg(short, long, default_value = "input.txt")]
+        input: PathBuf,
+        /// The words that count as digits
+        #[arg(short, long, value_enum, default_value_t = Dictionary::English)]
+        dictionary: Dictionary,
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Digit words for `explain`, always including the digits themselves.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Dictionary {
+    /// Only digits, like part 1
+    Digits,
+    /// Like part 2
+    English,
+    German,
+    French,
+}
+
+impl Dictionary {
+    fn recognizer(self) -> Recognizer {
+        match self {
+            Dictionary::Digits => Recognizer::new(recognizer::DIGITS),
+            Dictionary::English => Recognizer::spelled(&recognizer::ENGLISH),
+            Dictionary::German => Recognizer::spelled(&recognizer::GERMAN),
+            Dictionary::French => Recognizer::spelled(&recognizer::FRENCH),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+enum FrameFormat {
+    #[default]
+    Ppm,
+    Svg,
+}
+
+/// Where to draw the puzzles, besides printing the answers.
+#[derive(Debug, Clone, Default)]
+struct Export {
+    svg: bool,
+    ppm: bool,
+    animate: Option<u32>,
+    frames: Option<PathBuf>,
+    frames_format: FrameFormat,
+}
+
+impl Export {
+    fn records(&self) -> bool {
+        self.animate.is_some() || self.frames.is_some()
+    }
+}
+
+/// At most this many frames are recorded per run.
+const FRAME_LIMIT: usize = 10_000;
+
+fn replay(day: &dyn Runnable, recorder: &anim::Recorder, export: &Export) -> Result<()> {
+    if recorder.is_empty() {
+        println!("  Day {:02} records no frames", day.day());
+        return Ok(());
+    }
+    if let Some(fps) = export.animate {
+        recorder.play(fps);
+    }
+    if let Some(directory) = &export.frames {
+        let saved = match export.frames_format {
+            FrameFormat::Ppm => recorder.save_ppm(directory, 512),
+            FrameFormat::Svg => recorder.save_svg(directory),
+        };
+        saved.into_diagnostic().wrap_err("Cannot write the frames")?;
+        println!("  {} frames saved to {}", recorder.len(), directory.display());
+    }
+    Ok(())
+}
+
+fn export(day: &dyn Runnable, content: &str, export: &Export) -> Result<()> {
+    if !export.svg && !export.ppm {
+        return Ok(());
+    }
+    let Some(image) = day.image(content)? else {
+        println!("  Day {:02} draws no picture", day.day());
+        return Ok(());
+    };
+    let mut saved = Vec::new();
+    if export.svg {
+        saved.push(image.save_svg(day.day()));
+    }
+    if export.ppm {
+        saved.push(image.save_ppm(day.day()));
+    }
+    for path in saved {
+        let path = path.into_diagnostic().wrap_err("Cannot write the picture")?;
+        println!("  Drawn to {}", path.display());
+    }
+    Ok(())
+}
+
+/// The parts of `wanted` to run on the input at `path`. Unless `unanswered` is set, parts without an accepted answer
+/// are left out for inputs listed in the day's `answers.toml`: they are unsolved or don't finish in reasonable time.
+fn answered_parts(day: u8, path: &Path, wanted: &[Part], unanswered: bool) -> Result<(Vec<Part>, Vec<Part>)> {
+    let answers = Answers::load(day)?;
+    let expected = match answers.for_input(day, path) {
+        Some(expected) if !unanswered => expected.parts(),
+        _ => return Ok((wanted.to_vec(), Vec::new())),
+    };
+    Ok(wanted.iter().partition(|part| expected.contains(part)))
+}
+
+fn run(selection: Selection, part: Option<u8>, inputs: Vec<PathBuf>, unanswered: bool, drawing: &Export) -> Result<()> {
+    let parts = match part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::all().to_vec(),
+    };
+    let inputs = if inputs.is_empty() { vec![PathBuf::from("input.txt")] } else { inputs };
+
+    let days = aoc::days();
+    let selected: Vec<_> = match selection {
+        Selection::All => days.iter().collect(),
+        Selection::Day(day) => {
+            let found: Vec<_> = days.iter().filter(|d| d.day() == day).collect();
+            if found.is_empty() {
+                return Err(miette!("Day {} is not solved (yet)", day));
+            }
+            found
+        }
+    };
+
+    let mut total = Duration::ZERO;
+    for day in selected {
+        for input in inputs.iter() {
+            let path = aoc::resolve_input(day.day(), input);
+            let content = input::load(&path)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("Cannot read {}", path.display()))?;
+
+            println!("Day {:02} ({})", day.day(), input.display());
+            let (parts, skipped) = answered_parts(day.day(), &path, &parts, unanswered)?;
+            if drawing.records() {
+                anim::start(FRAME_LIMIT);
+            }
+            let outcome = day.run(&content, &parts);
+            let recorder = anim::stop();
+            let outcome = outcome?;
+            println!("  Parse:  {:.2?}", outcome.parse);
+            total += outcome.parse;
+            for (part, answer) in outcome.answers.iter() {
+                println!("  {}: {} ({:.2?})", part, answer.value, answer.elapsed);
+                total += answer.elapsed;
+            }
+            for part in skipped {
+                println!("  {}: skipped, no accepted answer (--unanswered runs it)", part);
+            }
+            export(day.as_ref(), &content, drawing)?;
+            if drawing.records() {
+                replay(day.as_ref(), &recorder, drawing)?;
+            }
+        }
+    }
+    println!("Total: {:.2?}", total);
+
+    Ok(())
+}
+
+fn explain(input: PathBuf, dictionary: Dictionary, json: bool) -> Result<()> {
+    let path = aoc::resolve_input(1, &input);
+    let content = input::load(&path)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("Cannot read {}", path.display()))?;
+
+    let reports = explain::explain(&content, &dictionary.recognizer());
+    println!("{}", if json { explain::json(&reports) } else { explain::table(&reports) });
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run { day, part, input, svg, ppm, animate, frames, frames_format, unanswered } => {
+            run(day, part, input, unanswered, &Export { svg, ppm, animate, frames, frames_format })
+        }
+        Command::Explain { input, dictionary, json } => explain(input, dictionary, json),
+    }
+}
@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+use aoc::answers::Answers;
+use tools::input;
+use tools::runner::{Part, Runnable};
+
+struct Row {
+    day: u8,
+    input: String,
+    part: Part,
+    expected: String,
+    actual: String,
+}
+
+impl Row {
+    fn is_ok(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// Runs the inputs listed in a day's `answers.toml` that pass `filter` and compares the answers.
+///
+/// `raw` gets the file content exactly as it is on disk and may mangle it before it's handed to the day.
+fn check(day: &dyn Runnable, filter: fn(&str) -> bool, raw: fn(String) -> String) -> Vec<Row> {
+    let answers = Answers::load(day.day()).unwrap_or_else(|e| panic!("{:?}", e));
+    let mut rows = Vec::new();
+    for (input, expected) in answers.iter().filter(|(input, _)| filter(input)) {
+        let parts = expected.parts();
+        let path = aoc::day_dir(day.day()).join(input);
+        let outcome = fs::read_to_string(&path)
+            .map(raw)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))
+            .and_then(|content| {
+                panic::catch_unwind(AssertUnwindSafe(|| day.run(&content, &parts)))
+                    .map_err(|_| "panicked".to_string())?
+                    .map_err(|e| format!("error: {}", e))
+            });
+
+        for part in parts {
+            let actual = match &outcome {
+                Ok(outcome) => outcome.answer(part).map(|a| a.value.clone()).unwrap_or_default(),
+                Err(e) => e.clone(),
+            };
+            rows.push(Row { day: day.day(), input: input.clone(), part, expected: expected.get(part).unwrap(), actual });
+        }
+    }
+
+    rows
+}
+
+fn table(rows: &[&Row]) -> String {
+    let header = ["Day", "Input", "Part", "Expected", "Actual"].map(String::from);
+    let lines: Vec<[String; 5]> = std::iter::once(header)
+        .chain(rows.iter().map(|row| {
+            [format!("{:02}", row.day), row.input.clone(), row.part.to_string(), row.expected.clone(), row.actual.clone()]
+        }))
+        .collect();
+    tools::table::aligned(&lines)
+}
+
+/// Checks all days in parallel and fails with a table of the wrong answers.
+fn check_all(filter: fn(&str) -> bool, raw: fn(String) -> String) {
+    let days = aoc::days();
+    let rows: Vec<Row> = thread::scope(|scope| {
+        let handles: Vec<_> = days.iter().map(|day| scope.spawn(|| check(day.as_ref(), filter, raw))).collect();
+        handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect()
+    });
+
+    assert!(!rows.is_empty(), "No answers.toml lists any answer");
+    let wrong: Vec<&Row> = rows.iter().filter(|row| !row.is_ok()).collect();
+    assert!(wrong.is_empty(), "{} of {} answers are wrong:\n{}", wrong.len(), rows.len(), table(&wrong));
+}
+
+#[test]
+fn answers_match() {
+    check_all(|_| true, |content| input::normalize(&content));
+}
+
+#[test]
+fn samples_with_crlf_and_trailing_newline() {
+    check_all(|input| input.starts_with("sample"), |content| content.replace("\r\n", "\n").replace('\n', "\r\n") + "\r\n");
+}
+
+#[test]
+fn answers_for_resolved_inputs() {
+    let answers = Answers::load(12).unwrap();
+    // Tests run from the aoc crate, so `../day12/sample.txt` is `day12/sample.txt` from the workspace root.
+    for input in ["sample.txt", "./sample.txt", "../day12/sample.txt"] {
+        let expected = answers.for_input(12, &aoc::resolve_input(12, Path::new(input)));
+        assert_eq!(Some(vec![Part::One]), expected.map(|expected| expected.parts()), "{}", input);
+    }
+    assert!(answers.for_input(12, &aoc::resolve_input(12, Path::new("Cargo.toml"))).is_none());
+    assert!(answers.for_input(12, &aoc::resolve_input(12, Path::new("missing.txt"))).is_none());
+}
@@ -0,0 +1,40 @@
+//! The days are libraries: their models and solvers can be used without going through `Solution`.
+
+use tools::interval::Interval;
+use tools::{input, Solution};
+
+fn sample(day: u8) -> String {
+    input::load(aoc::day_dir(day).join("sample.txt")).unwrap()
+}
+
+#[test]
+fn day01_calibration_values() {
+    assert_eq!(Some(38), day01::calibration_value("pqr3stu8vwx"));
+    assert_eq!(None, day01::calibration_value("trebuchet"));
+    assert_eq!(Some(83), day01::spelled_calibration_value("eightwothree"));
+    assert_eq!(None, day01::spelled_calibration_value("abc"));
+}
+
+#[test]
+fn day05_almanac_maps() {
+    let almanac = day05::Day05::parse(&sample(5)).unwrap();
+    assert_eq!(81, almanac.seed_to_soil.get(79));
+    assert_eq!(82, almanac.map_seed(79).location);
+}
+
+#[test]
+fn day13_smudged_mirror() {
+    let content = sample(13);
+    let pattern = day13::Pattern::parse(input::blocks(&content)[0]).unwrap();
+    assert_eq!(Some(5), pattern.mirrored().map(|mirrored| mirrored.score()));
+    assert_eq!(Some(300), pattern.mirrored_smudged().map(|mirrored| mirrored.score()));
+}
+
+#[test]
+fn day19_workflows() {
+    let system = day19::Day19::parse(&sample(19)).unwrap();
+    assert!(system.check_part(&system.parts[0]).unwrap());
+
+    let accepted = system.run_though_workflow("in", day19::PartRange::<4>::cube(Interval::new(1, 4001))).unwrap();
+    assert_eq!(167409079868000, accepted.iter().map(|range| range.volume().unwrap()).sum::<u128>());
+}
@@ -0,0 +1,59 @@
+
+use crate::custom_error::AocError;
+use miette::Result;
+use nom::character::complete::not_line_ending;
+use nom::combinator::cut;
+use tools::parse::{self, IResult, Span};
+use tools::Solution;
+pub mod custom_error;
+
+type Parsed = String;
+type AocResult = Int;
+type AocResult2 = AocResult;
+type Int = usize;
+
+fn parse_input(input: Span) -> IResult<Parsed> {
+    let (input, lines) = parse::lines(cut(not_line_ending))(input)?;
+    todo!()
+}
+
+pub struct {{project-name | capitalize}};
+
+impl Solution for {{project-name | capitalize}} {
+    const DAY: u8 = {{project-name | remove: "day" | plus: 0}};
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        parse::finish(parse_input, content).map_err(|e| AocError::parse_error(content, e))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        println!("{:?}", root);
+        todo!("Implement Part 1");
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        todo!("Implement Part 2");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::{input, Solution};
+
+    use crate::{{project-name | capitalize}};
+
+    #[test]
+    fn tests() -> miette::Result<()> {
+        let file = "sample.txt";
+        let content = input::load(file).expect("Cannot read file");
+        let root = {{project-name | capitalize}}::parse(&content)?;
+        assert_eq!(0, {{project-name | capitalize}}::part1(&root)?);
+        assert_eq!(0, {{project-name | capitalize}}::part2(&root)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! Explains the calibration value of every line, to find the line at fault when the sum is wrong.
+
+use serde::Serialize;
+
+use crate::recognizer::{Match, Recognizer, Source};
+
+/// How one input line was read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LineReport {
+    /// Counting from 1.
+    pub line: usize,
+    pub text: String,
+    pub first: Option<Match>,
+    pub last: Option<Match>,
+    /// `None` for a line without any digit.
+    pub value: Option<u32>,
+    pub no_digit: bool,
+}
+
+/// One report per line of `content`, in order.
+pub fn explain(content: &str, recognizer: &Recognizer) -> Vec<LineReport> {
+    tools::input::lines(content)
+        .enumerate()
+        .map(|(index, text)| {
+            let first = recognizer.first_match(text);
+            let last = recognizer.last_match(text);
+            let value = first.zip(last).map(|(first, last)| first.value * 10 + last.value);
+            LineReport { line: index + 1, text: text.to_string(), first, last, value, no_digit: value.is_none() }
+        })
+        .collect()
+}
+
+fn describe(found: &Option<Match>) -> [String; 3] {
+    match found {
+        Some(found) => {
+            let source = match found.source {
+                Source::Digit => "digit",
+                Source::Word => "word",
+            };
+            [found.value.to_string(), format!("{}..{}", found.start, found.end), source.to_string()]
+        }
+        None => ["-".to_string(), "-".to_string(), "-".to_string()],
+    }
+}
+
+/// An aligned table with a row per line, ending in the sum of the values and how many lines have no digit.
+pub fn table(reports: &[LineReport]) -> String {
+    let header = ["Line", "First", "At", "From", "Last", "At", "From", "Value", "Text"].map(String::from);
+    let rows: Vec<[String; 9]> = std::iter::once(header)
+        .chain(reports.iter().map(|report| {
+            let [first, first_at, first_from] = describe(&report.first);
+            let [last, last_at, last_from] = describe(&report.last);
+            let value = report.value.map_or("NO DIGIT".to_string(), |value| value.to_string());
+            [report.line.to_string(), first, first_at, first_from, last, last_at, last_from, value, report.text.clone()]
+        }))
+        .collect();
+    let mut table = tools::table::aligned(&rows);
+
+    let sum: u32 = reports.iter().filter_map(|report| report.value).sum();
+    let missing = reports.iter().filter(|report| report.no_digit).count();
+    table.push_str(&format!("\nSum: {}, lines without a digit: {}", sum, missing));
+    table
+}
+
+/// The reports as a JSON array.
+pub fn json(reports: &[LineReport]) -> String {
+    serde_json::to_string_pretty(reports).expect("reports always serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::recognizer::ENGLISH;
+
+    use super::*;
+
+    #[test]
+    fn explain_lines() {
+        let reports = explain("a1b2\nxsevenz\nnothing\n", &Recognizer::spelled(&ENGLISH));
+        let last = reports[0].last.unwrap();
+        assert_eq!((2, 3, 4, Source::Digit), (last.value, last.start, last.end, last.source));
+        assert_eq!(Some(Source::Word), reports[1].first.map(|found| found.source));
+        assert_eq!((None, true), (reports[2].value, reports[2].no_digit));
+
+        assert!(table(&reports).ends_with("Sum: 89, lines without a digit: 1"));
+        assert!(json(&reports).contains("\"no_digit\": true"));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::{input, Solution};
+
+pub mod custom_error;
+pub mod explain;
+pub mod recognizer;
+
+pub use recognizer::Recognizer;
+use recognizer::ENGLISH;
+
+type Parsed = Vec<String>;
+type AocResult = u32;
+type AocResult2 = AocResult;
+
+/// The first and last digit of `line` as a two digit number. `None` without any digit.
+pub fn calibration_value(line: &str) -> Option<u32> {
+    let mut first = None;
+    let mut last = None;
+    // iterate over characters in string
+    line.chars().for_each(|c| {
+        // check if character is a number
+        c.to_digit(10).and_then(|d| {
+            if first.is_none() {
+                first = Some(d);
+            }
+            last = Some(d);
+            None::<()>
+        });
+    });
+
+    Some(first? * 10 + last?)
+}
+
+/// Like [`calibration_value`], but digits may also be spelled out as `one` to `nine`. `None` without any digit.
+pub fn spelled_calibration_value(line: &str) -> Option<u32> {
+    Recognizer::spelled(&ENGLISH).calibration_value(line)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        Ok(input::lines(content).map(|s| s.to_string()).collect())
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        root.iter()
+            .enumerate()
+            .map(|(index, line)| calibration_value(line).ok_or_else(|| AocError::invalid_line(index, "no digit")))
+            .sum()
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let recognizer = Recognizer::spelled(&ENGLISH);
+        root.iter()
+            .enumerate()
+            .map(|(index, line)| recognizer.calibration_value(line).ok_or_else(|| AocError::invalid_line(index, "no digit")))
+            .sum()
+    }
+}
@@ -0,0 +1,270 @@
+//! Finds the first and last digit of a calibration line, written as a digit or as any word of a dictionary.
+//!
+//! The words go into an Aho–Corasick automaton, so a line is scanned once instead of trying every word at every
+//! position, and overlapping words like `eightwo` are both found. The last digit comes from a second automaton over
+//! the reversed words, scanning the line backwards.
+
+use std::collections::{HashMap, VecDeque};
+
+use serde::Serialize;
+
+pub const DIGITS: [(&str, u32); 10] = [
+    ("0", 0), ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9),
+];
+
+pub const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+];
+
+pub const GERMAN: [(&str, u32); 9] = [
+    ("eins", 1), ("zwei", 2), ("drei", 3), ("vier", 4), ("fünf", 5), ("sechs", 6), ("sieben", 7), ("acht", 8), ("neun", 9),
+];
+
+pub const FRENCH: [(&str, u32); 9] = [
+    ("un", 1), ("deux", 2), ("trois", 3), ("quatre", 4), ("cinq", 5), ("six", 6), ("sept", 7), ("huit", 8), ("neuf", 9),
+];
+
+#[derive(Debug, Default)]
+struct State {
+    next: HashMap<u8, usize>,
+    /// The longest proper suffix of this state that is also a state.
+    fail: usize,
+    /// The length and value of the longest word ending here.
+    word: Option<(usize, u32)>,
+}
+
+/// Aho–Corasick automaton over the bytes of the words.
+#[derive(Debug)]
+struct Automaton {
+    states: Vec<State>,
+    longest: usize,
+}
+
+impl Automaton {
+    fn new(words: &[(Vec<u8>, u32)]) -> Automaton {
+        let mut states = vec![State::default()];
+        for (word, value) in words {
+            let mut state = 0;
+            for byte in word {
+                state = match states[state].next.get(byte) {
+                    Some(next) => *next,
+                    None => {
+                        states.push(State::default());
+                        let next = states.len() - 1;
+                        states[state].next.insert(*byte, next);
+                        next
+                    }
+                };
+            }
+            states[state].word = Some((word.len(), *value));
+        }
+
+        // Breadth first, so the fail state of a state is done before the state itself.
+        let mut queue: VecDeque<usize> = states[0].next.values().copied().collect();
+        while let Some(state) = queue.pop_front() {
+            let next: Vec<(u8, usize)> = states[state].next.iter().map(|(byte, next)| (*byte, *next)).collect();
+            for (byte, next) in next {
+                let mut fail = states[state].fail;
+                while fail != 0 && !states[fail].next.contains_key(&byte) {
+                    fail = states[fail].fail;
+                }
+                let fail = states[fail].next.get(&byte).copied().unwrap_or(0);
+                states[next].fail = fail;
+                if states[next].word.is_none() {
+                    states[next].word = states[fail].word;
+                }
+                queue.push_back(next);
+            }
+        }
+
+        let longest = words.iter().map(|(word, _)| word.len()).max().unwrap_or(0);
+        Automaton { states, longest }
+    }
+
+    fn step(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if let Some(next) = self.states[state].next.get(&byte) {
+                return *next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    /// The start, length and value of the word starting first in `bytes`, preferring the longest one.
+    fn leftmost(&self, bytes: impl Iterator<Item = u8>) -> Option<(usize, usize, u32)> {
+        let mut state = 0;
+        let mut found: Option<(usize, usize, u32)> = None;
+        for (index, byte) in bytes.enumerate() {
+            // Words ending from here on are too short to start before the one found.
+            if found.is_some_and(|(start, _, _)| index >= start + self.longest) {
+                break;
+            }
+
+            state = self.step(state, byte);
+            if let Some((length, value)) = self.states[state].word {
+                let start = index + 1 - length;
+                if found.is_none_or(|(first, _, _)| start <= first) {
+                    found = Some((start, length, value));
+                }
+            }
+        }
+
+        found
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Digit,
+    Word,
+}
+
+/// A recognized word in a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Match {
+    pub value: u32,
+    /// Byte offsets into the line.
+    pub start: usize,
+    pub end: usize,
+    pub source: Source,
+}
+
+impl Match {
+    fn new(line: &str, start: usize, length: usize, value: u32) -> Match {
+        let end = start + length;
+        let source = if line[start..end].bytes().all(|byte| byte.is_ascii_digit()) { Source::Digit } else { Source::Word };
+        Match { value, start, end, source }
+    }
+}
+
+/// Recognizes the words of a dictionary, each standing for a digit.
+#[derive(Debug)]
+pub struct Recognizer {
+    forward: Automaton,
+    backward: Automaton,
+}
+
+impl Recognizer {
+    /// Recognizes exactly `words`, which may be anything from `zero` to custom tokens. Digits are only recognized if
+    /// they are among them, see [`DIGITS`]. Every word stands for a single digit, so tens words stand for their leading
+    /// digit: `("twenty", 2)`, `("seventeen", 1)`.
+    ///
+    /// # Panics
+    ///
+    /// If a word has a value above 9.
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = (S, u32)>) -> Recognizer {
+        let words: Vec<(Vec<u8>, u32)> = words.into_iter()
+            .map(|(word, value)| {
+                assert!(value <= 9, "{:?} stands for {}, not a digit", word.as_ref(), value);
+                (word.as_ref().bytes().collect(), value)
+            })
+            .collect();
+        let reversed: Vec<(Vec<u8>, u32)> = words.iter().map(|(word, value)| (word.iter().rev().copied().collect(), *value)).collect();
+
+        Recognizer { forward: Automaton::new(&words), backward: Automaton::new(&reversed) }
+    }
+
+    /// Digits and the words of `language`, like [`ENGLISH`].
+    pub fn spelled(language: &[(&str, u32)]) -> Recognizer {
+        Recognizer::new(DIGITS.iter().chain(language).copied())
+    }
+
+    /// The word starting first.
+    pub fn first_match(&self, line: &str) -> Option<Match> {
+        let (start, length, value) = self.forward.leftmost(line.bytes())?;
+        Some(Match::new(line, start, length, value))
+    }
+
+    /// The word ending last.
+    pub fn last_match(&self, line: &str) -> Option<Match> {
+        let (end, length, value) = self.backward.leftmost(line.bytes().rev())?;
+        Some(Match::new(line, line.len() - end - length, length, value))
+    }
+
+    /// The value of the word starting first.
+    pub fn first(&self, line: &str) -> Option<u32> {
+        self.first_match(line).map(|found| found.value)
+    }
+
+    /// The value of the word ending last.
+    pub fn last(&self, line: &str) -> Option<u32> {
+        self.last_match(line).map(|found| found.value)
+    }
+
+    /// The first and last digit as a two digit number. `None` without any digit.
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        Some(self.first(line)? * 10 + self.last(line)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::input;
+
+    use super::*;
+
+    fn automaton(words: &[(&str, u32)]) -> Automaton {
+        Automaton::new(&words.iter().map(|(word, value)| (word.bytes().collect(), *value)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn leftmost() {
+        // `even` ends first, but `seventeen` starts before it.
+        let words = automaton(&[("even", 3), ("ten", 2), ("seventeen", 1)]);
+        assert_eq!(Some((0, 9, 1)), words.leftmost("seventeen".bytes()));
+        assert_eq!(Some((1, 4, 3)), words.leftmost("xeventeen".bytes()));
+
+        // The longest of the words starting at the same place.
+        let words = automaton(&[("seven", 7), ("seventeen", 1)]);
+        assert_eq!(Some((2, 9, 1)), words.leftmost("..seventeen".bytes()));
+        assert_eq!(Some((2, 5, 7)), words.leftmost("..seventee".bytes()));
+
+        // Found through the fail links of `hers`.
+        let words = automaton(&[("he", 1), ("she", 2), ("hers", 3)]);
+        assert_eq!(Some((1, 3, 2)), words.leftmost("ushers".bytes()));
+
+        assert_eq!(Some((1, 5, 5)), automaton(&GERMAN).leftmost("xfünf".bytes()));
+        assert_eq!(None, automaton(&ENGLISH).leftmost("xyz".bytes()));
+        assert_eq!(None, automaton(&[]).leftmost("one".bytes()));
+    }
+
+    #[test]
+    fn dictionaries() {
+        let english = Recognizer::spelled(&ENGLISH);
+        assert_eq!((Some(8), Some(2)), (english.first("xeightwo"), english.last("xeightwo")));
+
+        let custom = Recognizer::new([("zero", 0), ("one", 1), ("seven", 7)]);
+        assert_eq!(Some(1), custom.calibration_value("zerone"));
+        assert_eq!(Some(77), custom.calibration_value("7sevenx"));
+    }
+
+    #[test]
+    fn tens_words() {
+        let tens = [("ten", 1), ("seventeen", 1), ("twenty", 2), ("thirty", 3), ("ninety", 9)];
+        let recognizer = Recognizer::new(ENGLISH.iter().chain(&tens).copied());
+        assert_eq!(Some(21), recognizer.calibration_value("twentyone"));
+        assert_eq!(Some(33), recognizer.calibration_value("xthirtyx"));
+        assert_eq!(Some(11), recognizer.calibration_value("seventeen"));
+        assert_eq!(Some(79), recognizer.calibration_value("sevenninety"));
+        assert_eq!(Some(91), recognizer.calibration_value("ninetyten"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\"twenty\" stands for 20, not a digit")]
+    fn tens_values() {
+        Recognizer::new([("twenty", 20)]);
+    }
+
+    #[test]
+    fn localized_fixtures() {
+        for (file, language, total) in [("sample_de.txt", GERMAN, 374), ("sample_fr.txt", FRENCH, 447)] {
+            let recognizer = Recognizer::spelled(&language);
+            let content = input::load(file).expect("Cannot read file");
+            assert_eq!(Some(total), input::lines(&content).map(|line| recognizer.calibration_value(line)).sum(), "{}", file);
+        }
+    }
+}
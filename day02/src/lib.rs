@@ -0,0 +1,273 @@
+use std::collections::BTreeMap;
+
+use crate::custom_error::AocError;
+use miette::Result;
+use nom::bytes::complete::take_till1;
+use nom::character::complete::{char, space0, space1};
+use nom::combinator::{consumed, cut, verify};
+use nom::multi::separated_list1;
+use nom::sequence::{separated_pair, tuple};
+use nom::Parser;
+use nom_supreme::tag::complete::tag;
+use nom_supreme::ParserExt;
+use tools::parse::{self, IResult, Span};
+use tools::Solution;
+
+pub mod custom_error;
+
+type Parsed = Vec<Game>;
+type AocResult = usize;
+type AocResult2 = u32;
+
+/// The colours whose product is a game's power.
+pub const RGB: [&str; 3] = ["red", "green", "blue"];
+
+/// Numbers of cubes by colour. Colours that aren't listed count 0.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Cubes(BTreeMap<String, u32>);
+
+impl Cubes {
+    pub fn new<S: Into<String>>(counts: impl IntoIterator<Item = (S, u32)>) -> Cubes {
+        let mut cubes = Cubes::default();
+        for (color, count) in counts {
+            cubes.add(color, count);
+        }
+        cubes
+    }
+
+    pub fn add(&mut self, color: impl Into<String>, count: u32) {
+        *self.0.entry(color.into()).or_default() += count;
+    }
+
+    pub fn get(&self, color: &str) -> u32 {
+        self.0.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.0.iter().map(|(color, count)| (color.as_str(), *count))
+    }
+
+    pub fn total(&self) -> u32 {
+        self.0.values().sum()
+    }
+
+    /// Keeps the larger count of every colour.
+    pub fn merge(&mut self, other: &Cubes) {
+        for (color, count) in other.iter() {
+            let mine = self.0.entry(color.to_string()).or_default();
+            *mine = (*mine).max(count);
+        }
+    }
+
+    /// Whether no colour exceeds the count in `bag`.
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.iter().all(|(color, count)| count <= bag.get(color))
+    }
+
+    /// The product of the counts of `colors`.
+    pub fn power(&self, colors: &[&str]) -> u32 {
+        colors.iter().map(|color| self.get(color)).product()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    /// The cubes shown by every pull, in order.
+    pub pulls: Vec<Cubes>,
+}
+
+impl Game {
+    /// The fewest cubes of every colour the bag must have held.
+    pub fn minimum_bag(&self) -> Cubes {
+        let mut bag = Cubes::default();
+        for pull in self.pulls.iter() {
+            bag.merge(pull);
+        }
+        bag
+    }
+
+    /// Whether every pull could have come from `bag`.
+    pub fn is_possible(&self, bag: &Cubes) -> bool {
+        self.pulls.iter().all(|pull| pull.fits_in(bag))
+    }
+}
+
+/// The games that could have been played with `bag`.
+pub fn possible_games<'a>(games: &'a [Game], bag: &'a Cubes) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(move |game| game.is_possible(bag))
+}
+
+/// The bag with the fewest cubes in total that makes at least `n` games possible. `None` if there aren't `n` games.
+pub fn smallest_bag(games: &[Game], n: usize) -> Option<Cubes> {
+    if n > games.len() {
+        return None;
+    }
+
+    let minimums: Vec<Cubes> = games.iter().map(Game::minimum_bag).collect();
+    let mut colors: Vec<&str> = minimums.iter().flat_map(|bag| bag.iter().map(|(color, _)| color)).collect();
+    colors.sort();
+    colors.dedup();
+
+    let mut search = BagSearch { colors, n, best: None };
+    search.search(&minimums.iter().collect::<Vec<_>>(), &mut Vec::new());
+    search.best
+}
+
+/// Branch and bound over the count of one colour after the other. Only counts some game needs are worth trying.
+struct BagSearch<'a> {
+    colors: Vec<&'a str>,
+    n: usize,
+    best: Option<Cubes>,
+}
+
+impl BagSearch<'_> {
+    /// The fewest cubes the colours from `from` on need to fit `n` of `games`.
+    fn lower_bound(&self, games: &[&Cubes], from: usize) -> u32 {
+        self.colors[from..].iter()
+            .map(|color| {
+                let mut counts: Vec<u32> = games.iter().map(|game| game.get(color)).collect();
+                counts.sort();
+                counts[self.n - 1]
+            })
+            .sum()
+    }
+
+    /// `counts` are chosen for the first colours, and `games` still fit them.
+    fn search(&mut self, games: &[&Cubes], counts: &mut Vec<u32>) {
+        let total: u32 = counts.iter().sum();
+        if self.n == 0 || counts.len() == self.colors.len() {
+            if self.best.as_ref().is_none_or(|best| total < best.total()) {
+                let counts = self.colors.iter().copied().zip(counts.iter().copied());
+                self.best = Some(Cubes::new(counts.filter(|(_, count)| *count > 0)));
+            }
+            return;
+        }
+        let best = self.best.as_ref().map_or(u32::MAX, Cubes::total);
+        if total + self.lower_bound(games, counts.len()) >= best {
+            return;
+        }
+
+        let color = self.colors[counts.len()];
+        let mut levels: Vec<u32> = games.iter().map(|game| game.get(color)).collect();
+        levels.sort();
+        levels.dedup();
+        for level in levels {
+            let fitting: Vec<&Cubes> = games.iter().copied().filter(|game| game.get(color) <= level).collect();
+            if fitting.len() < self.n {
+                continue;
+            }
+            counts.push(level);
+            self.search(&fitting, counts);
+            counts.pop();
+        }
+    }
+}
+
+/// A word up to the next separator, like `blue`.
+fn color(input: Span) -> IResult<Span> {
+    verify(take_till1(|c: char| c == ',' || c == ';' || c.is_whitespace()), |word: &Span| {
+        word.chars().all(char::is_alphabetic)
+    }).context("colour").parse(input)
+}
+
+/// `3 blue`
+fn cubes(input: Span) -> IResult<(u32, Span)> {
+    separated_pair(parse::integer, space1, cut(color)).parse(input)
+}
+
+/// `3 blue, 4 red`
+fn pull(input: Span) -> IResult<Cubes> {
+    parse::comma_separated(cut(cubes))
+        .map(|counts| Cubes::new(counts.into_iter().map(|(count, color)| (color.to_string(), count))))
+        .parse(input)
+}
+
+/// `Game 1: 3 blue, 4 red; 1 red, 2 green`, with where the id is written.
+fn game(input: Span) -> IResult<(Span, Game)> {
+    let pulls = separated_list1(tuple((space0, char(';'), space0)), cut(pull));
+    parse::labeled(tag("Game").precedes(space1).precedes(consumed(parse::integer::<u32>)), pulls)
+        .map(|((written, id), pulls)| (written, Game { id, pulls }))
+        .parse(input)
+}
+
+/// The games must be numbered 1, 2, 3, … without repeating or skipping one.
+fn check_ids(content: &str, games: &[(Span, Game)]) -> Result<(), AocError> {
+    for (expected, (written, game)) in (1..).zip(games) {
+        let message = if game.id == 0 {
+            "games are numbered from 1".to_string()
+        } else if game.id < expected {
+            format!("game {} appears twice", game.id)
+        } else if game.id > expected + 1 {
+            format!("games {} to {} are missing", expected, game.id - 1)
+        } else if game.id > expected {
+            format!("game {} is missing", expected)
+        } else {
+            continue;
+        };
+        return Err(AocError::located(content, written.location_offset(), written.fragment().len(), message));
+    }
+    Ok(())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let games = parse::finish(parse::lines(cut(game)), content).map_err(|e| AocError::parse_error(content, e))?;
+        check_ids(content, &games)?;
+        Ok(games.into_iter().map(|(_, game)| game).collect())
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        let bag = Cubes::new([("red", 12), ("green", 13), ("blue", 14)]);
+        Ok(possible_games(root, &bag).map(|game| game.id as usize).sum())
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        Ok(root.iter().map(|game| game.minimum_bag().power(&RGB)).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::input;
+
+    use super::*;
+
+    #[test]
+    fn bag_queries() {
+        let games = Day02::parse(&input::load("sample.txt").expect("Cannot read file")).unwrap();
+        let bag = Cubes::new([("red", 12), ("green", 13), ("blue", 14)]);
+        assert_eq!(vec![1, 2, 5], possible_games(&games, &bag).map(|game| game.id).collect::<Vec<_>>());
+        assert_eq!(Cubes::new([("red", 4), ("green", 2), ("blue", 6)]), games[0].minimum_bag());
+
+        assert_eq!(Some(Cubes::new([("red", 1), ("green", 3), ("blue", 4)])), smallest_bag(&games, 1));
+        assert_eq!(Some(13), smallest_bag(&games, 2).map(|bag| bag.total()));
+        assert_eq!(Some(48), smallest_bag(&games, 5).map(|bag| bag.total()));
+        assert_eq!(None, smallest_bag(&games, 6));
+
+        let games = Day02::parse("Game 1: 2 cyan, 1 red; 3 magenta\nGame 2: 5 cyan").unwrap();
+        assert_eq!(Some(Cubes::new([("cyan", 5)])), smallest_bag(&games, 1));
+        assert_eq!(Some(Cubes::new([("cyan", 5), ("magenta", 3), ("red", 1)])), smallest_bag(&games, 2));
+        assert_eq!(6, games[0].minimum_bag().power(&["cyan", "magenta"]));
+    }
+    #[test]
+    fn located_errors() {
+        let error = |content: &str| match Day02::parse(content) {
+            Err(AocError::ParseError { span, message, .. }) => (span.offset(), message),
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+
+        assert_eq!((34, "game 2 appears twice".to_string()), error("Game 1: 3 blue\nGame 2: 1 red\nGame 2: 5 red\n"));
+        assert_eq!((20, "games 2 to 3 are missing".to_string()), error("Game 1: 3 blue\nGame 4: 1 red"));
+        assert_eq!((25, "expected colour".to_string()), error("Game 1: 3 blue, 4 red; 2 gr33n"));
+        assert_eq!(23, error("Game 1: 3 blue\nGame 2: x red").0);
+    }
+}
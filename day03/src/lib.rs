@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+
+use crate::custom_error::AocError;
+use glam::IVec2;
+use miette::Result;
+use tools::grid::Grid;
+use tools::Solution;
+
+pub mod custom_error;
+
+type Parsed = Schematic;
+type AocResult = u32;
+type AocResult2 = u64;
+
+/// A number written in the schematic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    /// Where the first digit is.
+    pub position: IVec2,
+    pub length: i32,
+}
+
+/// Any character that is neither a digit nor `.`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub position: IVec2,
+}
+
+/// The numbers and symbols of a schematic, with an edge between every number and every symbol it touches,
+/// diagonals included. Numbers and symbols are referred to by their index.
+#[derive(Debug)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// The symbols touching every number.
+    number_edges: Vec<Vec<usize>>,
+    /// The numbers touching every symbol.
+    symbol_edges: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn new(grid: &Grid<char>) -> Schematic {
+        let mut symbols = Vec::new();
+        let mut symbol_at = grid.map(|_| None);
+        for (position, c) in grid.iter() {
+            if *c != '.' && !c.is_ascii_digit() {
+                symbol_at[position] = Some(symbols.len());
+                symbols.push(Symbol { symbol: *c, position });
+            }
+        }
+
+        let mut numbers: Vec<Number> = Vec::new();
+        for (y, row) in grid.rows().enumerate() {
+            let mut current = None;
+            for (x, c) in row.iter().enumerate() {
+                let Some(digit) = c.to_digit(10) else {
+                    current = None;
+                    continue;
+                };
+                let index = *current.get_or_insert_with(|| {
+                    numbers.push(Number { value: 0, position: IVec2::new(x as i32, y as i32), length: 0 });
+                    numbers.len() - 1
+                });
+                numbers[index].value = numbers[index].value * 10 + digit;
+                numbers[index].length += 1;
+            }
+        }
+
+        let mut symbol_edges = vec![Vec::new(); symbols.len()];
+        let number_edges: Vec<Vec<usize>> = numbers.iter()
+            .enumerate()
+            .map(|(index, number)| {
+                let mut edges = Vec::new();
+                for y in number.position.y - 1..=number.position.y + 1 {
+                    for x in number.position.x - 1..=number.position.x + number.length {
+                        if let Some(Some(symbol)) = symbol_at.get(IVec2::new(x, y)) {
+                            edges.push(*symbol);
+                            symbol_edges[*symbol].push(index);
+                        }
+                    }
+                }
+                edges
+            })
+            .collect();
+
+        Schematic { numbers, symbols, number_edges, symbol_edges }
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The symbols touching the number at `index`.
+    pub fn symbols_of(&self, index: usize) -> &[usize] {
+        &self.number_edges[index]
+    }
+
+    /// The numbers touching the symbol at `index`.
+    pub fn numbers_of(&self, index: usize) -> &[usize] {
+        &self.symbol_edges[index]
+    }
+
+    /// The values of the numbers touching the symbol at `index`.
+    pub fn values_of(&self, index: usize) -> impl Iterator<Item = u32> + '_ {
+        self.symbol_edges[index].iter().map(|number| self.numbers[*number].value)
+    }
+
+    /// The numbers touching any symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().zip(&self.number_edges).filter(|(_, edges)| !edges.is_empty()).map(|(number, _)| number)
+    }
+
+    /// The symbols written as `symbol` that touch exactly `k` numbers, or all symbols for `None`.
+    pub fn symbols_with(&self, symbol: Option<char>, k: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.symbols.len()).filter(move |index| {
+            symbol.is_none_or(|symbol| self.symbols[*index].symbol == symbol) && self.symbol_edges[*index].len() == k
+        })
+    }
+
+    /// For every kind of symbol, `value` of the numbers touching each such symbol, added up.
+    pub fn per_symbol(&self, value: impl Fn(&[u32]) -> u64) -> BTreeMap<char, u64> {
+        let mut totals = BTreeMap::new();
+        for (index, symbol) in self.symbols.iter().enumerate() {
+            let values: Vec<u32> = self.values_of(index).collect();
+            *totals.entry(symbol.symbol).or_default() += value(&values);
+        }
+        totals
+    }
+
+    /// The sum of the products of the numbers around each `symbol` touching exactly `k` numbers. Gear ratios are
+    /// `ratios('*', 2)`.
+    pub fn ratios(&self, symbol: char, k: usize) -> u64 {
+        self.symbols_with(Some(symbol), k)
+            .map(|index| self.values_of(index).map(u64::from).product::<u64>())
+            .sum()
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let grid = Grid::parse(content, |_, c| Ok::<_, AocError>(c))?;
+        Ok(Schematic::new(&grid))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        Ok(root.part_numbers().map(|number| number.value).sum())
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        Ok(root.ratios('*', 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::input;
+
+    use super::*;
+
+    #[test]
+    fn schematic_graph() {
+        let schematic = Day03::parse(&input::load("sample.txt").expect("Cannot read file")).unwrap();
+        assert_eq!(8, schematic.part_numbers().count());
+        assert_eq!(2, schematic.symbols_with(Some('*'), 2).count());
+        assert_eq!(4, schematic.symbols_with(None, 1).count());
+        assert_eq!(633, schematic.ratios('#', 1));
+
+        let sums = schematic.per_symbol(|values| values.iter().copied().map(u64::from).sum());
+        assert_eq!((Some(&2472), Some(&664)), (sums.get(&'*'), sums.get(&'$')));
+
+        let schematic = Day03::parse("#12\n...\n..5").unwrap();
+        assert_eq!(&[0], schematic.numbers_of(0));
+        assert!(schematic.symbols_of(1).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use std::cmp::min;
+use std::collections::HashMap;
+
+use crate::custom_error::AocError;
+use miette::Result;
+use nom::character::complete::{char, space1};
+use nom::combinator::cut;
+use nom::sequence::{separated_pair, tuple};
+use nom::Parser;
+use nom_supreme::tag::complete::tag;
+use nom_supreme::ParserExt;
+use tools::parse::{self, IResult, Span};
+use tools::Solution;
+
+pub mod custom_error;
+
+type Parsed = Vec<Card>;
+type AocResult = u32;
+type AocResult2 = AocResult;
+
+#[derive(Debug)]
+pub struct Card {
+    pub winning: Vec<u32>,
+    pub drawn: Vec<u32>,
+}
+
+/// `Card 1: 41 48 83 | 83 86 6`
+fn card(input: Span) -> IResult<Card> {
+    let numbers = separated_pair(parse::integers, tuple((space1, char('|'), space1)), parse::integers);
+    parse::labeled(tag("Card").precedes(space1).precedes(parse::integer::<u32>), numbers)
+        .map(|(_, (winning, drawn))| Card { winning, drawn })
+        .parse(input)
+}
+
+impl Card {
+
+    /// How many drawn numbers are winning numbers.
+    pub fn match_count(&self) -> u32 {
+        let mut matching: Vec<u32> = Vec::new();
+        for number in self.drawn.iter() {
+            if self.winning.contains(number) {
+                matching.push(*number);
+            }
+        }
+        matching.len() as u32
+    }
+
+    pub fn score(&self) -> u32 {
+        let matching = self.match_count();
+
+        if matching == 0 {
+            0
+        } else if matching == 1 {
+            1
+        } else {
+            let base: u32 = 2;
+            base.pow(matching-1)
+        }
+
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        parse::finish(parse::lines(cut(card)), content).map_err(|e| AocError::parse_error(content, e))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+        let mut sum = 0;
+        for game in root {
+            //println!("{}", game.score());
+            sum += game.score();
+        }
+        Ok(sum)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let mut counts: HashMap<usize, u32> = HashMap::new();
+        let last_index = root.len() - 1;
+
+        for (index, game) in root.iter().enumerate() {
+            let count = game.match_count();
+
+            if count > 0 && index < last_index {
+                let copies = *counts.entry(index).or_insert(1);
+                for sub in index+1..=min(index+count as usize, last_index) {
+                    *counts.entry(sub).or_insert(1) += copies;
+                }
+            }
+        }
+
+        let mut sum = 0;
+
+        for index in 0..=last_index {
+            sum += *counts.entry(index).or_insert(1);
+        }
+
+        Ok(sum)
+    }
+}
@@ -0,0 +1,187 @@
+use crate::custom_error::AocError;
+use miette::Result;
+use nom::bytes::complete::is_not;
+use nom::character::complete::{line_ending, multispace1, space1};
+use nom::combinator::cut;
+use nom::sequence::{preceded, separated_pair, tuple};
+use nom::Parser;
+use nom_supreme::tag::complete::tag;
+use tools::interval::{Interval, IntervalSet};
+use tools::parse::{self, IResult, Span};
+use tools::Solution;
+
+pub mod custom_error;
+
+type Parsed = Almanac;
+type AocResult = u64;
+type AocResult2 = AocResult;
+
+/// Moves the values of `source` so that its start lands on `destination`.
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    pub source: Interval<u64>,
+    pub destination: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct AlmanacMap(pub Vec<Mapping>);
+
+impl AlmanacMap {
+    /// Where the first mapping whose source contains `value` moves it, or `value` itself.
+    pub fn get(&self, value: u64) -> u64 {
+        self.0.iter()
+            .find(|mapping| mapping.source.contains(value))
+            .map_or(value, |mapping| value - mapping.source.start + mapping.destination)
+    }
+
+    /// Maps every value of `values` at once. Values outside of all sources stay as they are.
+    pub fn apply(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let sources: IntervalSet<u64> = self.0.iter().map(|mapping| mapping.source).collect();
+        let mut mapped = values.difference(&sources);
+        for mapping in self.0.iter() {
+            let moved = values.intersection(&mapping.source.into()).shift(mapping.source.start, mapping.destination);
+            mapped = mapped.union(&moved);
+        }
+
+        mapped
+    }
+}
+
+#[derive(Debug)]
+pub struct MappedSeed {
+    pub seed: u64,
+    pub soil: u64,
+    pub fertilizer: u64,
+    pub water: u64,
+    pub light: u64,
+    pub temperature: u64,
+    pub humidity: u64,
+    pub location: u64,
+}
+
+#[derive(Debug)]
+pub struct MappedSeeds(pub Vec<MappedSeed>);
+
+impl MappedSeeds {
+    pub fn lowest_location(&self) -> Option<u64> {
+        self.0.iter().map(|seed| seed.location).min()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub seed_to_soil: AlmanacMap,
+    pub soil_to_fertilizer: AlmanacMap,
+    pub fertilizer_to_water: AlmanacMap,
+    pub water_to_light: AlmanacMap,
+    pub light_to_temperature: AlmanacMap,
+    pub temperature_to_humidity: AlmanacMap,
+    pub humidity_to_location: AlmanacMap,
+}
+
+impl Almanac {
+    pub fn map_seed(&self, seed: u64) -> MappedSeed {
+        let soil = self.seed_to_soil.get(seed);
+        let fertilizer = self.soil_to_fertilizer.get(soil);
+        let water = self.fertilizer_to_water.get(fertilizer);
+        let light = self.water_to_light.get(water);
+        let temperature = self.light_to_temperature.get(light);
+        let humidity = self.temperature_to_humidity.get(temperature);
+        let location = self.humidity_to_location.get(humidity);
+
+        MappedSeed{seed, soil, fertilizer, water, light, temperature, humidity, location}
+    }
+
+    pub fn map_seeds(&self) -> MappedSeeds {
+        let mut mapped = Vec::new();
+        for seed in self.seeds.iter() {
+            mapped.push(self.map_seed(*seed));
+        }
+
+        MappedSeeds(mapped)
+    }
+
+    pub fn seed_ranges(&self) -> IntervalSet<u64> {
+        self.seeds.chunks_exact(2)
+            .map(|pair| Interval::with_length(pair[0], pair[1]))
+            .collect()
+    }
+
+    pub fn locations(&self, seeds: &IntervalSet<u64>) -> IntervalSet<u64> {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location,
+        ]
+            .iter()
+            .fold(seeds.clone(), |values, map| map.apply(&values))
+    }
+}
+
+fn mapping(input: Span) -> IResult<Mapping> {
+    tuple((parse::integer, space1, parse::integer, space1, parse::integer))
+        .map(|(destination, _, source, _, length)| Mapping { source: Interval::with_length(source, length), destination })
+        .parse(input)
+}
+
+/// The lines of a map after its `x-to-y map:` header.
+fn almanac_map(input: Span) -> IResult<AlmanacMap> {
+    parse::labeled(is_not(":\n"), preceded(line_ending, parse::lines(cut(mapping))))
+        .map(|(_, mappings)| AlmanacMap(mappings))
+        .parse(input)
+}
+
+fn parse_almanac(input: Span) -> IResult<(Vec<u64>, Vec<AlmanacMap>)> {
+    separated_pair(parse::labeled(tag("seeds"), parse::integers), multispace1, parse::blocks(almanac_map))
+        .map(|((_, seeds), maps)| (seeds, maps))
+        .parse(input)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let (seeds, maps) = parse::finish(parse_almanac, content).map_err(|e| AocError::parse_error(content, e))?;
+        let [
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        ] = <[AlmanacMap; 7]>::try_from(maps)
+            .map_err(|maps| AocError::Malformed(format!("expected seven maps, found {}", maps.len())))?;
+
+        Ok(Almanac {
+            seeds,
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        })
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:#?}", root);
+        root.map_seeds().lowest_location().ok_or(AocError::NoSolution)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        root.locations(&root.seed_ranges()).min().ok_or(AocError::NoSolution)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::custom_error::AocError;
+use miette::Result;
+use nom::character::complete::line_ending;
+use nom::sequence::separated_pair;
+use nom::Parser;
+use nom_supreme::tag::complete::tag;
+use tools::parse::{self, IResult, Span};
+use tools::Solution;
+
+pub mod custom_error;
+
+type Parsed = Races;
+type AocResult = u128;
+type AocResult2 = AocResult;
+
+#[derive(Debug)]
+pub struct Race {
+    pub time: u128,
+    pub distance: u128,
+}
+
+impl Race {
+    /// The number of ways to beat the record distance.
+    pub fn run(&self) -> u128 {
+        let mut wins = 0;
+        for speed in 1..self.time {
+            let distance = speed * (self.time - speed);
+            if distance > self.distance {
+                wins += 1;
+            }
+        }
+
+        wins
+    }
+}
+
+#[derive(Debug)]
+pub struct Races {
+    pub races: Vec<Race>,
+    /// The single race of part 2, read with the spaces between digits ignored.
+    pub race: Race,
+}
+
+impl Races {
+    pub fn score1(&self) -> u128 {
+        self.races.iter().map(|race| race.run()).product()
+    }
+}
+
+fn parse_races(input: Span) -> IResult<(Vec<u128>, Vec<u128>)> {
+    separated_pair(
+        parse::labeled(tag("Time"), parse::integers),
+        line_ending,
+        parse::labeled(tag("Distance"), parse::integers),
+    ).map(|((_, times), (_, distances))| (times, distances)).parse(input)
+}
+
+/// The numbers written next to each other, as if the spaces between them weren't there.
+fn joined(numbers: &[u128]) -> Result<u128, AocError> {
+    let digits: String = numbers.iter().map(|number| number.to_string()).collect();
+    digits.parse().map_err(|_| AocError::Malformed(format!("{} is too long for a race", digits)))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let (times, distances) = parse::finish(parse_races, content).map_err(|e| AocError::parse_error(content, e))?;
+        if times.len() != distances.len() {
+            return Err(AocError::Malformed(format!("{} times but {} distances", times.len(), distances.len())));
+        }
+
+        let races = times.iter().zip(distances.iter()).map(|(time, distance)| Race { time: *time, distance: *distance }).collect();
+        let race = Race { time: joined(&times)?, distance: joined(&distances)? };
+        Ok(Races { races, race })
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+
+        //println!("{:?}", root);
+        Ok(root.score1())
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+
+        Ok(root.race.run())
+    }
+}
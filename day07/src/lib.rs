@@ -0,0 +1,233 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::{input, Solution};
+
+pub mod custom_error;
+
+type Parsed = Game;
+type AocResult = u32;
+type AocResult2 = AocResult;
+
+#[derive(Debug, PartialOrd, PartialEq, Eq, Ord, Clone)]
+pub enum HandType {
+    FiveOfAKind = 7,
+    // 1
+    FourOfAKind = 6,
+    // 2
+    FullHouse = 5,
+    // 2
+    ThreeOfAKind = 4,
+    // 3
+    TwoPair = 3,
+    // 3
+    OnePair = 2,
+    // 4
+    HighCard = 1, // 5
+}
+
+impl HandType {
+    pub fn from(cards: &[u32]) -> Option<Self> {
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+
+        for card in cards.iter() {
+            let count = counts.entry(*card).or_insert(0);
+            *count += 1;
+        }
+
+        match counts.len() {
+            5 => Some(HandType::HighCard),
+            4 => Some(HandType::OnePair),
+            3 => {
+                let mut result = None;
+                for (_, count) in counts.iter() {
+                    if *count == 3 {
+                        result = Some(HandType::ThreeOfAKind);
+                        break;
+                    }
+                    if *count == 2 {
+                        result = Some(HandType::TwoPair);
+                        break;
+                    }
+                }
+                result
+            }
+            2 => match counts.iter().next() {
+                Some((_, count)) if *count == 2 || *count == 3 => Some(HandType::FullHouse),
+                Some((_, count)) if *count == 1 || *count == 4 => Some(HandType::FourOfAKind),
+                _ => None
+            },
+            1 => Some(HandType::FiveOfAKind),
+            _ => None
+        }
+    }
+
+    /// The best type the hand can have when jokers, valued 1, may stand in for any card.
+    pub fn from_joker(cards: &[u32]) -> Option<Self> {
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+
+        for card in cards.iter() {
+            let count = counts.entry(*card).or_insert(0);
+            *count += 1;
+        }
+
+        let Some(&joker) = counts.get(&1) else {
+            return HandType::from(cards);
+        };
+
+        let length = counts.len();
+        counts.remove(&1);
+
+        match length {
+            5 => Some(HandType::OnePair),
+            4 => Some(HandType::ThreeOfAKind),
+            3 => {
+                match joker {
+                    1 => match counts.iter().next() {
+                        Some((_, count)) if *count == 1 || *count == 3 => Some(HandType::FourOfAKind),
+                        _ => Some(HandType::FullHouse),
+                    },
+                    _ => Some(HandType::FourOfAKind)
+                }
+            }
+            2 => Some(HandType::FiveOfAKind),
+            1 => Some(HandType::FiveOfAKind),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Eq, Clone)]
+pub struct Hand {
+    pub cards: Vec<u32>,
+    pub bid: u32,
+    _type: HandType,
+}
+
+impl Hand {
+    /// Reads `32T3K 765`, five cards and a bid. With `part2`, `J` is a joker worth less than any other card.
+    pub fn parse(line: &str, part2: bool) -> Option<Self> {
+        let parts = line.split_once(" ")?;
+        let mut cards = Vec::new();
+        let bid = parts.1.parse::<u32>().ok()?;
+        for character in parts.0.chars() {
+            let value = match character {
+                '2' => Some(2),
+                '3' => Some(3),
+                '4' => Some(4),
+                '5' => Some(5),
+                '6' => Some(6),
+                '7' => Some(7),
+                '8' => Some(8),
+                '9' => Some(9),
+                'T' => Some(10),
+                'J' => if part2 { Some(1) } else { Some(11) },
+                'Q' => Some(12),
+                'K' => Some(13),
+                'A' => Some(14),
+                _ => None,
+            }?;
+            cards.push(value);
+        }
+        if cards.len() != 5 {
+            return None;
+        }
+        Some(Hand { bid, _type: (if part2 { HandType::from_joker(&cards) } else { HandType::from(&cards) })?, cards })
+    }
+
+    pub fn hand_type(&self) -> &HandType {
+        &self._type
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards == other.cards && self._type == other._type
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let _type = self._type.cmp(&other._type);
+        match _type {
+            Ordering::Equal => self.cards.cmp(&other.cards),
+            differ => differ,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// The hands read without and with jokers.
+pub struct Game(pub Vec<Hand>, pub Vec<Hand>);
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let mut hands = Vec::new();
+        let mut hands_2 = Vec::new();
+        for (index, line) in input::lines(content).enumerate() {
+            let invalid = || AocError::invalid_line(index, "expected five cards and a bid");
+            hands.push(Hand::parse(line, false).ok_or_else(invalid)?);
+            hands_2.push(Hand::parse(line, true).ok_or_else(invalid)?);
+        }
+        Ok(Game(hands, hands_2))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        let mut game = root.clone();
+        game.0.sort();
+        //println!("{:?}", game);
+
+        let mut winnings = 0;
+        for (index, hand) in game.0.iter().enumerate() {
+            winnings += hand.bid * (index as u32 + 1);
+        }
+
+        Ok(winnings)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let mut game = root.clone();
+        game.1.sort();
+        //println!("{:#?}", game.1);
+
+        let mut winnings = 0;
+        for (index, hand) in game.1.iter().enumerate() {
+            winnings += hand.bid * (index as u32 + 1);
+        }
+
+        Ok(winnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::Solution;
+
+    use crate::custom_error::AocError;
+    use crate::{Day07, Hand};
+
+    #[test]
+    fn five_cards() {
+        assert!(Hand::parse("32T3K 765", false).is_some());
+        for line in ["AAAAAA 5", "AAAA 5", "32T3KQ 765", " 765"] {
+            assert!(Hand::parse(line, false).is_none(), "{}", line);
+        }
+        assert!(matches!(Day07::parse("32T3K 765\nAAAAAA 5\n"), Err(AocError::InvalidLine { line: 2, .. })));
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use crate::custom_error::AocError;
+use miette::Result;
+use itertools::Itertools;
+use nom::character::complete::{alphanumeric1, char, multispace1, one_of};
+use nom::combinator::cut;
+use nom::multi::many1;
+use nom::sequence::{separated_pair, tuple};
+use nom::Parser;
+use nom_supreme::tag::complete::tag;
+use tools::parse::{self, IResult, Span};
+use tools::{cycle, math, Solution};
+
+pub mod custom_error;
+
+//type Parsed = String;
+type AocResult = u32;
+type AocResult2 = u128;
+
+#[derive(Debug)]
+pub struct Parsed {
+    pub directions: Vec<char>,
+    pub nodes: HashMap<String, Node>
+}
+
+#[derive(Debug)]
+pub struct Node {
+    pub left: String,
+    pub right: String,
+}
+
+impl Node {
+    /// The left node for `L`, the right one for `R`.
+    pub fn next(&self, direction: char) -> &String {
+        if direction == 'L' { &self.left } else { &self.right }
+    }
+}
+
+/// `AAA = (BBB, CCC)`
+fn node(input: Span) -> IResult<(String, Node)> {
+    tuple((alphanumeric1, tag(" = ("), alphanumeric1, tag(", "), alphanumeric1, char(')')))
+        .map(|(name, _, left, _, right, _): (Span, _, Span, _, Span, _)| {
+            (name.to_string(), Node { left: left.to_string(), right: right.to_string() })
+        })
+        .parse(input)
+}
+
+/// The directions, then one node per line.
+type Network = (Vec<char>, Vec<(String, Node)>);
+
+fn parse_network(input: Span) -> IResult<Network> {
+    separated_pair(many1(one_of("LR")), multispace1, parse::lines(cut(node)))(input)
+}
+
+/// Where a ghost stands and how far it got through the directions.
+type State<'a> = (&'a str, usize);
+
+/// The steps at which a ghost stands on a `Z` node.
+#[derive(Debug)]
+pub struct Ghost {
+    /// Hits before the ghost enters its loop, which happen only once.
+    pub once: Vec<i128>,
+    /// Hits inside the loop, which repeat every `period` steps.
+    pub repeating: Vec<i128>,
+    pub period: i128,
+}
+
+impl Ghost {
+    /// Follows the directions from `start` until the ghost loops.
+    pub fn new(start: &str, root: &Parsed) -> Self {
+        let step = |(node, index): &State| {
+            let next = root.nodes[*node].next(root.directions[*index]);
+            (next.as_str(), (index + 1) % root.directions.len())
+        };
+
+        let initial = (start, 0);
+        let cycle = cycle::find(&initial, step);
+        let (mut once, mut repeating) = (Vec::new(), Vec::new());
+        let mut state = initial;
+        for index in 0..cycle.start + cycle.length {
+            if state.0.ends_with('Z') {
+                if index < cycle.start { &mut once } else { &mut repeating }.push(index as i128);
+            }
+            state = step(&state);
+        }
+
+        Ghost { once, repeating, period: cycle.length as i128 }
+    }
+
+    pub fn at_z(&self, steps: i128) -> bool {
+        self.once.contains(&steps)
+            || self.repeating.iter().any(|hit| steps >= *hit && (steps - hit) % self.period == 0)
+    }
+}
+
+/// The first step at which every ghost stands on a `Z` node.
+pub fn all_at_z(ghosts: &[Ghost]) -> Option<i128> {
+    // Hits before some ghost is in its loop can only be found by trying them.
+    let early = ghosts.iter()
+        .flat_map(|ghost| ghost.once.iter())
+        .copied()
+        .filter(|steps| ghosts.iter().all(|ghost| ghost.at_z(*steps)))
+        .min();
+    if early.is_some() {
+        return early;
+    }
+
+    // Afterwards every combination of looping hits is a system of congruences.
+    ghosts.iter()
+        .map(|ghost| ghost.repeating.iter().map(|hit| (*hit, ghost.period)).collect::<Vec<_>>())
+        .multi_cartesian_product()
+        .filter_map(|congruences| {
+            let earliest = congruences.iter().map(|(hit, _)| *hit).max()?;
+            let (steps, modulus) = math::crt(congruences)?;
+            Some(if steps < earliest { steps + (earliest - steps + modulus - 1) / modulus * modulus } else { steps })
+        })
+        .min()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let (directions, nodes) = parse::finish(parse_network, content).map_err(|e| AocError::parse_error(content, e))?;
+        let nodes: HashMap<String, Node> = nodes.into_iter().collect();
+        if let Some(unknown) = nodes.values().flat_map(|node| [&node.left, &node.right]).find(|next| !nodes.contains_key(*next)) {
+            return Err(AocError::UnknownNode(unknown.clone()));
+        }
+        Ok(Parsed { directions, nodes })
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+
+        let (mut current_node, _) = root.nodes.get_key_value("AAA").ok_or(AocError::MissingStart)?;
+        // After visiting every node at every direction the walk only repeats itself.
+        let limit = root.directions.len() * root.nodes.len();
+        for (steps, direction) in root.directions.iter().cycle().enumerate().take(limit + 1) {
+            if current_node == "ZZZ" {
+                return Ok(steps as AocResult);
+            }
+            current_node = root.nodes[current_node].next(*direction);
+        }
+
+        Err(AocError::NoSolution)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let ghosts: Vec<_> = root.nodes.keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|node| Ghost::new(node, root))
+            .collect();
+
+        all_at_z(&ghosts).map(|steps| steps as AocResult2).ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{all_at_z, Ghost};
+
+    #[test]
+    fn offsets_and_several_z_nodes() {
+        // Z at step 1 before the loop, then at 5, 7, 11, 13, …
+        let first = Ghost { once: vec![1], repeating: vec![5, 7], period: 6 };
+        // Z at 3, 7, 11, …
+        let second = Ghost { once: vec![], repeating: vec![3], period: 4 };
+        assert_eq!(Some(7), all_at_z(&[first, second]));
+
+        let never = Ghost { once: vec![], repeating: vec![1], period: 2 };
+        let even = Ghost { once: vec![], repeating: vec![2], period: 4 };
+        assert_eq!(None, all_at_z(&[never, even]));
+    }
+}
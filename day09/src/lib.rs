@@ -0,0 +1,95 @@
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::{parse, Solution};
+
+pub mod custom_error;
+
+type Parsed = Vec<Line>;
+type AocResult = i64;
+type AocResult2 = AocResult;
+
+#[derive(Debug)]
+pub struct Line {
+    values: Vec<i64>,
+    derivatives: Vec<Vec<i64>>,
+}
+
+impl Line {
+    /// Panics without values.
+    pub fn new(values: Vec<i64>) -> Line {
+        assert!(!values.is_empty(), "a history needs values");
+
+        let mut derivatives = Vec::new();
+        let mut previous_derivatives = values.clone();
+        loop {
+            let mut current_derivatives = Vec::new();
+
+            let mut all_zero = true;
+            for i in 1..previous_derivatives.len() {
+                let diff = previous_derivatives[i] - previous_derivatives[i-1];
+                if diff != 0 {
+                    all_zero = false;
+                }
+                current_derivatives.push(diff);
+            }
+            if all_zero {
+                break;
+            }
+            previous_derivatives = current_derivatives.clone();
+            derivatives.push(current_derivatives);
+        }
+
+        Line { values, derivatives }
+    }
+
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+
+    /// The value extrapolated after the last one.
+    pub fn next_number(&self) -> i64 {
+        self.values.last().unwrap() + self.derivatives.iter().map(|d| d.last().unwrap()).sum::<i64>()
+    }
+
+    /// The value extrapolated before the first one.
+    pub fn prev_number(&self) -> i64 {
+        self.values.first().unwrap() - self.derivatives.iter().rev().map(|d| d.first().unwrap()).fold(0, |acc, el| {
+            el - acc
+        })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let lines = parse::finish(parse::lines(parse::integers), content).map_err(|e| AocError::parse_error(content, e))?;
+        Ok(lines.into_iter().map(Line::new).collect())
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        let mut sum = 0;
+
+        for line in root.iter() {
+            sum += line.next_number();
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let mut sum = 0;
+
+        for line in root.iter() {
+            sum += line.prev_number();
+        }
+
+        Ok(sum)
+    }
+}
@@ -0,0 +1,205 @@
+use NodeType::{BottomLeft, BottomRight, Horizontal, Start, TopLeft, TopRight, Vertical};
+use tools::Direction::{Down, Left, Right, Up};
+
+use crate::custom_error::AocError;
+use glam::IVec2;
+use miette::Result;
+use tools::grid::Grid;
+use tools::polygon::Polygon;
+use tools::render::{Color, Image, Render, Rgb};
+use tools::{Direction, Solution};
+
+pub mod custom_error;
+
+type Parsed = Maze;
+type AocResult = isize;
+type AocResult2 = usize;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NodeType {
+    Start,
+    Horizontal,
+    Vertical,
+    BottomLeft,
+    BottomRight,
+    TopLeft,
+    TopRight,
+}
+
+impl NodeType {
+    pub fn parse(c: char) -> Option<NodeType> {
+        match c {
+            'S' => Some(Start),
+            '|' => Some(Vertical),
+            '-' => Some(Horizontal),
+            '7' => Some(BottomLeft),
+            'F' => Some(BottomRight),
+            'J' => Some(TopLeft),
+            'L' => Some(TopRight),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            Start => 'S',
+            Vertical => '┃',
+            Horizontal => '━',
+            BottomLeft => '┓',
+            BottomRight => '┏',
+            TopLeft => '┛',
+            TopRight => '┗',
+        }
+    }
+
+    /// Where a pipe leads when entered from `coming_from`, if it connects there.
+    pub fn next(&self, coming_from: &Direction) -> Option<Direction> {
+        match self {
+            Start => None,
+            Horizontal => match coming_from {
+                Up => None,
+                Down => None,
+                Left => Some(Right),
+                Right => Some(Left),
+            },
+            Vertical => match coming_from {
+                Left => None,
+                Right => None,
+                Up => Some(Down),
+                Down => Some(Up),
+            },
+            BottomLeft => match coming_from {
+                Left => Some(Down),
+                Right => None,
+                Up => None,
+                Down => Some(Left),
+            },
+            BottomRight => match coming_from {
+                Up => None,
+                Down => Some(Right),
+                Left => None,
+                Right => Some(Down),
+            },
+            TopLeft => match coming_from {
+                Up => Some(Left),
+                Down => None,
+                Left => Some(Up),
+                Right => None
+            },
+            TopRight => match coming_from {
+                Up => Some(Right),
+                Down => None,
+                Left => None,
+                Right => Some(Up),
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    nodes: Grid<Option<NodeType>>,
+    start: IVec2,
+    /// Tiles of the loop in walking order, starting at `start`.
+    the_loop: Vec<IVec2>,
+}
+
+impl Maze {
+    pub fn nodes(&self) -> &Grid<Option<NodeType>> {
+        &self.nodes
+    }
+
+    pub fn start(&self) -> IVec2 {
+        self.start
+    }
+
+    /// Tiles of the loop in walking order, starting at the start.
+    pub fn the_loop(&self) -> &[IVec2] {
+        &self.the_loop
+    }
+
+    fn find_next(&self, node: &IVec2, to: &Direction) -> Option<(IVec2, Option<Direction>)> {
+        let next = *node + to.delta();
+        let next_type = self.nodes.get(next)?.clone()?;
+        let direction = if next_type == Start { None } else { Some(next_type.next(&to.opposite())?) };
+        Some((next, direction))
+    }
+
+    fn find_loop(&mut self) -> Result<(), AocError> {
+        for direction in Direction::all() {
+            let mut current = self.start;
+            let mut next = direction;
+            let mut the_loop = Vec::new();
+            loop {
+                the_loop.push(current);
+                let Some((position, direction)) = self.find_next(&current, &next) else {
+                    break;
+                };
+                current = position;
+                if current == self.start {
+                    self.the_loop = the_loop;
+                    return Ok(());
+                }
+                // Only the start has no direction to go on, and it ends the loop.
+                let Some(direction) = direction else {
+                    break;
+                };
+                next = direction;
+            }
+        }
+        Err(AocError::NoSolution)
+    }
+
+    /// Tiles enclosed by the loop: Pick's theorem on the polygon through the tile centres.
+    pub fn calculate_area(&self) -> usize {
+        let polygon = Polygon::new(self.the_loop.iter().map(|tile| tile.as_i64vec2()).collect());
+        polygon.interior() as usize
+    }
+
+    /// Pipes outside of the loop are greyed out.
+    pub fn draw(&self) {
+        Render::new(&self.nodes, |_, node| node.as_ref().map_or('·', NodeType::symbol))
+            .layer(Color::Gray, |_| true)
+            .highlight(Color::Yellow, self.the_loop.iter().copied())
+            .print();
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let nodes = Grid::parse_sparse(content, '.', NodeType::parse)?;
+        let start = nodes.find(|node| *node == Some(Start)).ok_or(AocError::MissingStart)?;
+        //println!("Start: {:?}", start);
+        let mut maze = Maze { nodes, start, the_loop: Vec::new() };
+
+        maze.find_loop()?;
+
+        Ok(maze)
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+        Ok(root.the_loop.len() as AocResult / 2)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        //println!("{:#?}", root.the_loop);
+        Ok(root.calculate_area())
+    }
+
+    /// Pipes in grey, with the inside of the loop shaded.
+    fn image(root: &Parsed) -> Option<Image> {
+        let mut image = Image::from_grid(&root.nodes, Rgb::WHITE, |_, node| node.as_ref().map(|_| Color::Gray.into()));
+        let centres = root.the_loop.iter().map(|tile| tile.as_dvec2() + 0.5);
+        image.shape(centres, Color::Red.into(), Some(Color::Blue.into()));
+        Some(image)
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::grid::Grid;
+use tools::Solution;
+
+pub mod custom_error;
+
+type Parsed = Galaxy;
+type AocResult = i128;
+type AocResult2 = AocResult;
+type Int = i128;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct Star(pub Int, pub Int);
+
+impl Star {
+    /// The Manhattan distance.
+    pub fn distance_to(&self, other: &Star) -> Int {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs()
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Galaxy {
+    stars: HashSet<Star>,
+    width: Int,
+    height: Int,
+}
+
+impl Galaxy {
+    pub fn stars(&self) -> &HashSet<Star> {
+        &self.stars
+    }
+
+    /// Moves the stars behind every empty row and column `by` further away.
+    pub fn expand(&mut self, by: Int) {
+        let mut columns = Vec::new();
+        for x in 0..self.width {
+            let mut empty = true;
+            for y in 0..self.height {
+                if self.stars.contains(&Star(x, y)) {
+                    empty = false;
+                    break;
+                }
+            };
+            if empty {
+                columns.push(x);
+            }
+        }
+
+        let mut rows = Vec::new();
+        for y in 0..self.height {
+            let mut empty = true;
+            for x in 0..self.width {
+                if self.stars.contains(&Star(x, y)) {
+                    empty = false;
+                    break;
+                }
+            };
+            if empty {
+                rows.push(y);
+            }
+        }
+
+        for x in columns.iter().rev() {
+            let mut new_stars = HashSet::new();
+            for star in self.stars.iter() {
+                if star.0 > *x {
+                    new_stars.insert(Star(star.0 + by, star.1));
+                } else {
+                    new_stars.insert(star.clone());
+                }
+            }
+            self.stars = new_stars;
+            self.width += 1;
+        }
+
+        for y in rows.iter().rev() {
+            let mut new_stars = HashSet::new();
+            for star in self.stars.iter() {
+                if star.1 > *y {
+                    new_stars.insert(Star(star.0, star.1 + by));
+                } else {
+                    new_stars.insert(star.clone());
+                }
+            }
+            self.stars = new_stars;
+            self.height += 1;
+        }
+    }
+
+    /// The sum of the distances between all pairs of stars.
+    pub fn sum_distances(&self) -> Int {
+        let stars: Vec<Star> = self.stars.iter().cloned().collect();
+        let mut sum = 0;
+
+        for i in 0..stars.len() {
+            for j in i+1..stars.len() {
+                sum += stars[i].distance_to(&stars[j]);
+            }
+        }
+
+        sum
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let sky: Grid<bool> = Grid::parse(content, |pos, ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            ch => Err(AocError::InvalidCell { pos, ch }),
+        })?;
+        let stars = sky.iter()
+            .filter(|(_, star)| **star)
+            .map(|(position, _)| Star(position.x as Int, position.y as Int))
+            .collect();
+        Ok(Galaxy { stars, width: sky.width() as Int, height: sky.height() as Int })
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+        let mut galaxy = root.clone();
+        galaxy.expand(1);
+        //println!("{:?}", galaxy);
+        Ok(galaxy.sum_distances())
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let mut galaxy = root.clone();
+        galaxy.expand(999999);
+        Ok(galaxy.sum_distances())
+    }
+}
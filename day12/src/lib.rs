@@ -0,0 +1,139 @@
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::{input, Solution};
+
+pub mod custom_error;
+
+type Parsed = Puzzle;
+type AocResult = usize;
+type AocResult2 = AocResult;
+
+#[derive(Debug)]
+pub struct Records(pub Vec<Record>);
+
+impl Records {
+    pub fn calculate_arrangements(&self) -> usize {
+        self.0.iter().map(|r| r.calculate_arrangements()).sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Damaged springs are `true`, unknown ones `None`.
+pub struct Springs(pub Vec<Option<bool>>);
+
+impl Springs {
+    /// The lengths of the runs of damaged springs, if no spring is unknown.
+    pub fn groups(&self) -> Option<Groups> {
+        let mut current = 0;
+        let mut groups = Vec::new();
+        for spring in self.0.iter() {
+            let spring = (*spring)?;
+            if spring {
+                current += 1;
+            } else {
+                if current > 0 {
+                    groups.push(current);
+                    current = 0;
+                }
+            }
+        }
+        if current > 0 {
+            groups.push(current);
+        }
+        Some(Groups(groups))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Groups(pub Vec<usize>);
+
+#[derive(Debug)]
+pub struct Record {
+    pub springs: Springs,
+    unknown: Vec<usize>,
+    pub groups: Groups,
+}
+
+impl Record {
+    pub fn parse(content: &str) -> Option<Record> {
+        let (springs, groups) = content.split_once(' ')?;
+        let mut unknown = Vec::new();
+        let springs = springs.chars().enumerate().map(|(index, c)| match c {
+            '#' => Some(Some(true)),
+            '.' => Some(Some(false)),
+            '?' => {
+                unknown.push(index);
+                Some(None)
+            }
+            _ => None,
+        }).collect::<Option<Vec<_>>>()?;
+
+        let groups = groups.split(',').map(|s| s.parse().ok()).collect::<Option<Vec<_>>>()?;
+
+        Some(Record { springs: Springs(springs), unknown, groups: Groups(groups) })
+    }
+
+    /// Reads the record unfolded five times.
+    pub fn parse2(content: &str) -> Option<Record> {
+        let (springs, groups) = content.split_once(' ')?;
+        Record::parse(&format!("{} {}", [springs; 5].join("?"), [groups; 5].join(",")))
+    }
+
+    /// The number of ways to fill in the unknown springs that match the groups.
+    pub fn calculate_arrangements(&self) -> usize {
+        let mut possibilities = vec![self.springs.clone()];
+        for unknown in self.unknown.iter() {
+            let mut new_possibilities = Vec::new();
+            for springs in possibilities.iter() {
+                let mut el = springs.clone();
+                el.0[*unknown] = Some(true);
+                new_possibilities.push(el);
+                el = springs.clone();
+                el.0[*unknown] = Some(false);
+                new_possibilities.push(el);
+            }
+            possibilities = new_possibilities;
+        }
+
+        let valid_possibilities = possibilities.iter().filter(|possibility| {
+            possibility.groups().unwrap() == self.groups
+        }).collect::<Vec<_>>();
+
+        valid_possibilities.len()
+    }
+}
+
+/// The records as given, and unfolded five times for part 2.
+#[derive(Debug)]
+pub struct Puzzle(pub Records, pub Records);
+
+fn parse_records(content: &str, record: fn(&str) -> Option<Record>) -> Result<Records, AocError> {
+    input::lines(content)
+        .enumerate()
+        .map(|(index, line)| record(line).ok_or_else(|| AocError::invalid_line(index, "expected springs and groups")))
+        .collect::<Result<_, _>>()
+        .map(Records)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        Ok(Puzzle(parse_records(content, Record::parse)?, parse_records(content, Record::parse2)?))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        // println!("{:?}", root);
+        Ok(root.0.calculate_arrangements())
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        Ok(root.1.calculate_arrangements())
+    }
+}
@@ -0,0 +1,291 @@
+use Orientation::{Horizontal, Vertical};
+use crate::IsMirrored::{No, Smudged, Yes};
+
+use crate::custom_error::AocError;
+use glam::IVec2;
+use miette::Result;
+use tools::grid::Grid;
+use tools::{input, Solution};
+
+pub mod custom_error;
+
+type Parsed = Patterns;
+type AocResult = isize;
+type AocResult2 = AocResult;
+type Int = isize;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// A line of reflection, after `index` columns or rows.
+pub struct Mirrored {
+    pub orientation: Orientation,
+    pub index: Int,
+}
+
+impl Mirrored {
+    pub fn score(&self) -> Int {
+        match *self {
+            Mirrored { orientation: Vertical, index: i } => i,
+            Mirrored { orientation: Horizontal, index: i } => i * 100,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Patterns(pub Vec<Pattern>);
+
+impl Patterns {
+    /// `None` if some pattern has no line of reflection.
+    pub fn get_score(&self) -> Option<Int> {
+        self.0.iter().map(|p| Some(p.mirrored()?.score())).sum()
+    }
+    pub fn get_smudged_score(&self) -> Option<Int> {
+        self.0.iter().map(|p| Some(p.mirrored_smudged()?.score())).sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum IsMirrored {
+    No,
+    Yes,
+    Smudged,
+}
+
+#[derive(Debug)]
+pub struct Pattern {
+    pub rocks: Grid<bool>,
+}
+
+impl Pattern {
+    pub fn parse(content: &str) -> Result<Pattern, AocError> {
+        let rocks = Grid::parse(content, |pos, ch| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            ch => Err(AocError::InvalidCell { pos, ch }),
+        })?;
+        Ok(Pattern { rocks })
+    }
+
+    pub fn width(&self) -> Int {
+        self.rocks.width() as Int
+    }
+
+    pub fn height(&self) -> Int {
+        self.rocks.height() as Int
+    }
+
+    pub fn is_rock(&self, x: Int, y: Int) -> bool {
+        self.rocks[IVec2::new(x as i32, y as i32)]
+    }
+
+    fn is_row_mirrored_at(&self, row: Int, x: Int) -> IsMirrored {
+        let mut state = Yes;
+        for offset in 0..self.width() {
+            let left = x - offset;
+            let right = x + offset + 1;
+            if left < 0 || right >= self.width() {
+                break;
+            }
+
+            let is_left = self.is_rock(left, row);
+            let is_right = self.is_rock(right, row);
+            if is_left != is_right {
+                state = match state {
+                    No => unreachable!(),
+                    Yes => Smudged,
+                    Smudged => No,
+                };
+                if state == No {
+                    break;
+                }
+            }
+        }
+
+        state
+    }
+
+    fn get_vertical_mirror_score(&self) -> Option<Int> {
+        'outer: for x in 0..self.width() - 1 {
+            if self.is_row_mirrored_at(0, x) == Yes {
+                for y in 1..self.height() {
+                    if self.is_row_mirrored_at(y, x) != Yes {
+                        continue 'outer;
+                    }
+                }
+                // println!("X {}", x);
+                return Some(x + 1);
+            }
+        }
+
+        None
+    }
+
+    fn get_vertical_mirror_scores(&self) -> Vec<Int> {
+        let mut result = Vec::new();
+        'outer: for x in 0..self.width() - 1 {
+            let mut base_result = self.is_row_mirrored_at(0, x);
+            if base_result != No {
+                for y in 1..self.height() {
+                    let iter_result = self.is_row_mirrored_at(y, x);
+                    base_result = match iter_result {
+                        No => No,
+                        Yes => base_result,
+                        Smudged => if base_result != Yes { No } else { Smudged },
+                    };
+                    if base_result == No {
+                        continue 'outer;
+                    }
+                }
+                // println!("X {}", x);
+                result.push(x + 1);
+            }
+        }
+
+        result
+    }
+
+    fn is_col_mirrored_at(&self, col: Int, y: Int) -> IsMirrored {
+        let mut state = Yes;
+        for offset in 0..self.height() {
+            let top = y - offset;
+            let bottom = y + offset + 1;
+            if top < 0 || bottom >= self.height() {
+                break;
+            }
+
+            let is_top = self.is_rock(col, top);
+            let is_bottom = self.is_rock(col, bottom);
+            if is_top != is_bottom {
+                state = match state {
+                    No => unreachable!(),
+                    Yes => Smudged,
+                    Smudged => No,
+                };
+                if state == No {
+                    break;
+                }
+            }
+        }
+
+        state
+    }
+
+    fn get_horizontal_mirror_score(&self) -> Option<Int> {
+        'outer: for y in 0..self.height() - 1 {
+            if self.is_col_mirrored_at(0, y) == Yes {
+                for x in 1..self.width() {
+                    if self.is_col_mirrored_at(x, y) != Yes {
+                        continue 'outer;
+                    }
+                }
+                // println!("Y {}", y);
+                return Some(y + 1);
+            }
+        }
+
+        None
+    }
+
+    fn get_horizontal_mirror_scores(&self) -> Vec<Int> {
+        let mut result = Vec::new();
+        'outer: for y in 0..self.height() - 1 {
+            let mut base_result = self.is_col_mirrored_at(0, y);
+            if base_result != No {
+                for x in 1..self.width() {
+                    let iter_result = self.is_col_mirrored_at(x, y);
+                    base_result = match iter_result {
+                        No => No,
+                        Yes => base_result,
+                        Smudged => if base_result != Yes { No } else { Smudged },
+                    };
+                    if base_result == No {
+                        continue 'outer;
+                    }
+                }
+                // println!("X {}", x);
+                result.push(y + 1);
+            }
+        }
+
+        result
+    }
+
+    pub fn mirrored(&self) -> Option<Mirrored> {
+        self.get_vertical_mirror_score()
+            .map(|i| Mirrored { orientation: Vertical, index: i })
+            .or_else(|| self.get_horizontal_mirror_score().map(|x| Mirrored { orientation: Horizontal, index: x }))
+    }
+
+    /// The other line of reflection, which appears when exactly one cell is flipped.
+    pub fn mirrored_smudged(&self) -> Option<Mirrored> {
+        let unsmudged = self.mirrored();
+
+        self.get_vertical_mirror_scores()
+            .iter()
+            .map(|i| Mirrored { orientation: Vertical, index: *i })
+            .filter(|el| Some(el) != unsmudged.as_ref())
+            .collect::<Vec<_>>()
+            .first().cloned()
+            .or_else(|| self.get_horizontal_mirror_scores()
+                .iter()
+                .map(|i| Mirrored { orientation: Horizontal, index: *i })
+                .filter(|el| Some(el) != unsmudged.as_ref())
+                .collect::<Vec<_>>()
+                .first().cloned()
+            )
+
+    }
+
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let patterns = input::blocks(content)
+            .into_iter()
+            .map(|block| Pattern::parse(block).map_err(|e| e.within(content, block)))
+            .collect::<Result<_, _>>()?;
+        Ok(Patterns(patterns))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+        root.get_score().ok_or(AocError::NoSolution)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        root.get_smudged_score().ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::Solution;
+
+    use crate::custom_error::AocError;
+    use crate::Day13;
+
+    #[test]
+    fn located_errors() {
+        let error = |content: &str| match Day13::parse(content) {
+            Err(AocError::ParseError { span, message, .. }) => (span.offset(), span.len(), message),
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        };
+
+        let first = "#.#\n.##\n\n";
+        assert_eq!((14, 1, "unexpected 'x'".to_string()), error(&format!("{}##.\n.x#\n", first)));
+        assert_eq!((17, 4, "expected 3 cells, found 4".to_string()), error(&format!("{}##.\r\n###.\r\n", first.replace('\n', "\r\n"))));
+    }
+}
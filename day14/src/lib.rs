@@ -0,0 +1,136 @@
+use std::fmt::{Display, Formatter};
+
+use glam::IVec2;
+use tools::Direction::{Down, Left, Right, Up};
+
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::grid::Grid;
+use tools::render::{Color, Render};
+use tools::{anim, cycle, Direction, Solution};
+
+pub mod custom_error;
+
+type Parsed = Platform;
+type AocResult = usize;
+type AocResult2 = AocResult;
+type Int = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Empty,
+    Rock,
+    Block,
+}
+
+impl Tile {
+    pub fn symbol(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Rock => 'O',
+            Tile::Block => '#',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform(pub Grid<Tile>);
+
+impl Platform {
+    pub fn render(&self, rocks: Color) -> Render<'_, Tile> {
+        Render::new(&self.0, |_, tile| tile.symbol()).layer(rocks, |position| self.0[position] == Tile::Rock)
+    }
+
+    pub fn print(&self) {
+        println!("--------");
+        self.render(Color::Yellow).print();
+    }
+
+    /// Rolls every rock to the top as far as it goes.
+    fn tilted_up(grid: &Grid<Tile>) -> Grid<Tile> {
+        let mut tilted = grid.clone();
+        for x in 0..grid.width() as i32 {
+            let mut free = 0;
+            for y in 0..grid.height() as i32 {
+                match grid[IVec2::new(x, y)] {
+                    Tile::Empty => {}
+                    Tile::Block => free = y + 1,
+                    Tile::Rock => {
+                        tilted[IVec2::new(x, y)] = Tile::Empty;
+                        tilted[IVec2::new(x, free)] = Tile::Rock;
+                        free += 1;
+                    }
+                }
+            }
+        }
+
+        tilted
+    }
+
+    /// Turns the platform so `direction` is on top, tilts it up and turns it back.
+    pub fn tilted(&self, direction: Direction) -> Platform {
+        let grid = &self.0;
+        let tilted = Platform(match direction {
+            Up => Self::tilted_up(grid),
+            Right => Self::tilted_up(&grid.rotated_counterclockwise()).rotated_clockwise(),
+            Down => Self::tilted_up(&grid.rotated_clockwise().rotated_clockwise()).rotated_clockwise().rotated_clockwise(),
+            Left => Self::tilted_up(&grid.rotated_clockwise()).rotated_counterclockwise(),
+        });
+        anim::frame(|| tilted.render(Color::Yellow));
+        tilted
+    }
+
+    /// One spin cycle. Its end is recorded with the rocks in green.
+    pub fn cycle(&self) -> Platform {
+        let cycled = self.tilted(Up).tilted(Left).tilted(Down).tilted(Right);
+        anim::frame(|| cycled.render(Color::Green));
+        cycled
+    }
+
+    /// The total load on the north support beams.
+    pub fn load(&self) -> Int {
+        self.0.iter()
+            .filter(|(_, tile)| **tile == Tile::Rock)
+            .map(|(position, _)| self.0.height() - position.y as Int)
+            .sum()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let grid = Grid::parse(content, |pos, ch| match ch {
+            'O' => Ok(Tile::Rock),
+            '#' => Ok(Tile::Block),
+            '.' => Ok(Tile::Empty),
+            ch => Err(AocError::InvalidCell { pos, ch }),
+        })?;
+        Ok(Platform(grid))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+        //root.print();
+        let tilted = root.tilted(Up);
+        //tilted.print();
+        Ok(tilted.load())
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let cycled = cycle::nth(root, Platform::cycle, 1_000_000_000);
+        Ok(cycled.load())
+    }
+}
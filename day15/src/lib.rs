@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use indexmap::IndexMap;
+use crate::Op::{Add, Remove};
+
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::{input, Solution};
+
+pub mod custom_error;
+
+type Parsed = Instructions;
+type AocResult = usize;
+type AocResult2 = AocResult;
+type Int = usize;
+
+#[derive(Debug)]
+pub struct Instructions(pub Vec<Instruction>);
+
+impl Instructions {
+    pub fn hash_sum(&self) -> Int {
+        self.0.iter().map(|x| x.hash()).sum()
+    }
+
+    /// Puts the lenses into their boxes.
+    pub fn execute(&self) -> Factory {
+        let mut factory = Factory::default();
+        for instruction in self.0.iter() {
+            // println!("{:#?}", factory);
+            let box_number = hash(&instruction.label);
+            let box_ = factory.boxes.entry(box_number).or_default();
+            match instruction.op {
+                Remove => {
+                    box_.lenses.shift_remove(&instruction.label);
+                }
+                Add(focal) => {
+                    box_.lenses.insert(instruction.label.clone(), focal);
+                }
+            }
+        }
+
+        factory
+    }
+}
+
+/// The HASH algorithm: a number from 0 to 255.
+pub fn hash(val: &str) -> Int {
+    let mut hash: Int = 0;
+
+    for c in val.chars() {
+        hash += c as u8 as Int;
+        hash *= 17;
+        hash %= 256;
+    }
+
+    hash
+}
+
+#[derive(Debug)]
+pub enum Op {
+    Remove,
+    Add(Int),
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub raw: String,
+    pub label: String,
+    pub op: Op,
+}
+
+impl Instruction {
+    /// Reads `rn=1` or `cm-`.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (op, label) = if let Some(label) = raw.strip_suffix('-') {
+            (Remove, label.to_string())
+        } else {
+            let parts = raw.split_once("=")?;
+            (Add(parts.1.parse().ok()?), parts.0.to_string())
+        };
+
+        Some(Instruction {raw: raw.to_string(), op, label})
+    }
+
+    pub fn hash(&self) -> Int {
+        hash(&self.raw)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LensBox {
+    /// Focal lengths by label, in the order the lenses sit in the box.
+    pub lenses: IndexMap<String, Int>
+}
+
+impl LensBox {
+    /// The focusing power of the lenses in box number `box_`, counted from 1.
+    pub fn power(&self, box_: Int) -> Int {
+        self.lenses.iter()
+            .enumerate()
+            .map(|(i, (_, focal))| {
+                let pow = box_ * focal * (i + 1);
+
+                // println!("{} {} {} {}", box_, i + 1, focal, pow);
+
+                pow
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Factory {
+    pub boxes: HashMap<Int, LensBox>
+}
+
+impl Factory {
+    pub fn power(&self) -> Int {
+        self.boxes.iter()
+            .map(|(pos, box_)| box_.power(pos + 1))
+            .sum()
+    }
+}
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let mut instructions = Vec::new();
+        let line = input::lines(content).next().ok_or_else(|| AocError::Malformed("no steps".to_string()))?;
+        for i in line.split(",") {
+            instructions.push(Instruction::parse(i).ok_or_else(|| AocError::Malformed(format!("invalid step {:?}", i)))?);
+        }
+        Ok(Instructions(instructions))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+        Ok(root.hash_sum())
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        // println!("{:#?}", root.execute());
+
+        Ok(root.execute().power())
+    }
+}
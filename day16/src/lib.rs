@@ -0,0 +1,217 @@
+use std::cmp::max;
+use enumset::EnumSet;
+use crate::Cell::{MirrorLB, MirrorLT, SplitterH, SplitterV};
+use tools::Direction::{Down, Left, Right, Up};
+
+use crate::custom_error::AocError;
+use glam::IVec2;
+use miette::Result;
+use tools::grid::Grid;
+use tools::render::{Color, Image, Render, Rgb};
+use tools::{anim, Direction, Solution};
+
+pub mod custom_error;
+
+type Parsed = Contraption;
+type AocResult = usize;
+type AocResult2 = AocResult;
+type Int = i32;
+
+#[derive(Debug)]
+pub enum Cell {
+    MirrorLB,
+    MirrorLT,
+    SplitterV,
+    SplitterH,
+}
+
+impl Cell {
+    pub fn parse(c: &char) -> Option<Self> {
+        match c {
+            '|' => Some(SplitterV),
+            '-' => Some(SplitterH),
+            '\\' => Some(MirrorLB),
+            '/' => Some(MirrorLT),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            MirrorLB => '\\',
+            MirrorLT => '/',
+            SplitterV => '|',
+            SplitterH => '-',
+        }
+    }
+
+    /// Where a beam heading in `direction` continues, and the second direction if it's split.
+    pub fn apply(&self, direction: Direction) -> (Direction, Option<Direction>) {
+        match direction {
+            Up => match self {
+                MirrorLB => (Left, None),
+                MirrorLT => (Right, None),
+                SplitterV => (Up, None),
+                SplitterH => (Left, Some(Right)),
+            }
+            Right => match self {
+                MirrorLB => (Down, None),
+                MirrorLT => (Up, None),
+                SplitterV => (Up, Some(Down)),
+                SplitterH => (Right, None),
+            }
+            Down => match self {
+                MirrorLB => (Right, None),
+                MirrorLT => (Left, None),
+                SplitterV => (Down, None),
+                SplitterH => (Right, Some(Left))
+            }
+            Left => match self {
+                MirrorLB => (Up, None),
+                MirrorLT => (Down, None),
+                SplitterV => (Down, Some(Up)),
+                SplitterH => (Left, None),
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Contraption {
+    cells: Grid<Option<Cell>>,
+}
+
+impl Contraption {
+    pub fn cells(&self) -> &Grid<Option<Cell>> {
+        &self.cells
+    }
+
+    /// Every tile with the directions beams passed it in.
+    pub fn energize(&self, beam: Beam) -> Visited {
+        let mut beams = vec![beam];
+        let mut visited = Visited(Grid::filled(self.cells.width(), self.cells.height(), EnumSet::new()));
+
+        while let Some(mut beam) = beams.pop() {
+            let mut valid = true;
+            while valid {
+                if beam.valid(self) && visited.visit(&beam) {
+                    break;
+                }
+                anim::frame(|| self.render(&visited, &beam));
+                let new_beam;
+                (new_beam, valid) = beam.next(self);
+                if let Some(new_beam) = new_beam {
+                    beams.push(new_beam);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Energized tiles in yellow and the moving beam in red.
+    pub fn render<'a>(&'a self, visited: &'a Visited, beam: &Beam) -> Render<'a, Option<Cell>> {
+        Render::new(&self.cells, |_, cell| cell.as_ref().map_or('.', Cell::symbol))
+            .layer(Color::Yellow, |position| !visited.0[position].is_empty())
+            .highlight(Color::Red, [beam.position])
+    }
+
+    /// The number of tiles `beam` energizes.
+    pub fn get_energy(&self, beam: Beam) -> usize {
+        self.energize(beam).0.iter().filter(|(_, directions)| !directions.is_empty()).count()
+    }
+}
+
+#[derive(Debug)]
+pub struct Visited(pub Grid<EnumSet<Direction>>);
+
+impl Visited {
+    /// Marks the beam's tile as passed in the beam's direction, and tells whether it already was.
+    fn visit(&mut self, beam: &Beam) -> bool {
+        !self.0[beam.position].insert(beam.direction)
+    }
+}
+
+/// A beam entering the tile after `position` in `direction`.
+#[derive(Debug)]
+pub struct Beam {
+    pub position: IVec2,
+    pub direction: Direction,
+}
+
+impl Beam {
+    fn next(&mut self, grid: &Contraption) -> (Option<Beam>, bool) {
+        self.position += self.direction.delta();
+
+        let el = grid.cells.get(self.position).and_then(Option::as_ref);
+
+        let mut splitted = None;
+        if let Some(cell) = el {
+            let (direction, splitted_direction) = cell.apply(self.direction);
+            self.direction = direction;
+            if let Some(direction) = splitted_direction {
+                splitted = Some(Beam {position: self.position, direction})
+            }
+        }
+
+        (splitted, self.valid(grid))
+    }
+
+    fn valid(&self, grid: &Contraption) -> bool {
+        grid.cells.contains(self.position)
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        Ok(Contraption { cells: Grid::parse_sparse(content, '.', |c| Cell::parse(&c))? })
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:?}", root);
+
+        let beam = Beam {position: IVec2::new(-1, 0), direction: Right};
+
+        Ok(root.get_energy(beam))
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        let mut max_energy = 0;
+
+        let (width, height) = (root.cells.width() as Int, root.cells.height() as Int);
+        for y in 0..height {
+            let beam = Beam {position: IVec2::new(-1, y), direction: Right};
+            max_energy = max(max_energy, root.get_energy(beam));
+            let beam = Beam {position: IVec2::new(width, y), direction: Left};
+            max_energy = max(max_energy, root.get_energy(beam));
+        }
+        for x in 0..width {
+            let beam = Beam {position: IVec2::new(x, -1), direction: Down};
+            max_energy = max(max_energy, root.get_energy(beam));
+            let beam = Beam {position: IVec2::new(x, height), direction: Up};
+            max_energy = max(max_energy, root.get_energy(beam));
+        }
+
+        Ok(max_energy)
+    }
+
+    /// The tiles energized in part 1, over the mirrors and splitters.
+    fn image(root: &Parsed) -> Option<Image> {
+        let visited = root.energize(Beam {position: IVec2::new(-1, 0), direction: Right});
+        Some(Image::from_grid(&visited.0, Rgb::BLACK, |position, directions| {
+            if !directions.is_empty() {
+                Some(Color::Yellow.into())
+            } else {
+                root.cells[position].as_ref().map(|_| Color::Gray.into())
+            }
+        }))
+    }
+}
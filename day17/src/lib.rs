@@ -0,0 +1,70 @@
+use crate::custom_error::AocError;
+use glam::IVec2;
+use miette::Result;
+use tools::grid::Grid;
+use tools::path::{Moves, Path};
+use tools::render::{Color, Image, Rgb};
+use tools::Solution;
+
+pub mod custom_error;
+
+type Parsed = City;
+type AocResult = isize;
+type AocResult2 = AocResult;
+type Int = isize;
+
+#[derive(Debug, Clone)]
+pub struct City {
+    heat_loss: Grid<Int>,
+}
+
+impl City {
+    pub fn heat_loss(&self) -> &Grid<Int> {
+        &self.heat_loss
+    }
+
+    /// The path from the top left to the bottom right block losing the least heat.
+    pub fn best_path(&self, moves: Moves) -> Option<Path<Int>> {
+        let target = self.heat_loss.size() - IVec2::ONE;
+        moves.shortest_path(&self.heat_loss, IVec2::ZERO, target, |_, loss| Some(*loss))
+    }
+
+    pub fn least_heat_loss(&self, moves: Moves) -> Option<Int> {
+        self.best_path(moves).map(|path| path.cost)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        let heat_loss = Grid::parse(content, |pos, ch| ch.to_digit(10).map(|loss| loss as Int).ok_or(AocError::InvalidCell { pos, ch }))?;
+        Ok(City { heat_loss })
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        root.least_heat_loss(Moves::new(1, 3)).ok_or(AocError::NoSolution)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        root.least_heat_loss(Moves::new(4, 10)).ok_or(AocError::NoSolution)
+    }
+
+    /// The path of part 1 over the city, darker blocks losing more heat.
+    fn image(root: &Parsed) -> Option<Image> {
+        let mut image = Image::from_grid(&root.heat_loss, Rgb::WHITE, |_, loss| {
+            let shade = 255 - 25 * *loss as u8;
+            Some(Rgb(shade, shade, shade))
+        });
+        for position in root.best_path(Moves::new(1, 3))?.positions {
+            image.cell(position, Color::Red.into());
+        }
+        Some(image)
+    }
+}
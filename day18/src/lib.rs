@@ -0,0 +1,201 @@
+use glam::{I64Vec2, IVec2};
+
+use miette::Result;
+use nom::bytes::complete::take_while_m_n;
+use nom::character::complete::{self, char, one_of, space1};
+use nom::combinator::{cut, map_opt, map_res};
+use nom::Parser;
+use nom::sequence::{delimited, tuple};
+use nom_supreme::tag::complete::tag;
+use nom_supreme::ParserExt;
+
+use tools::grid::Grid;
+use tools::parse::{self, IResult, Span};
+use tools::polygon::Polygon;
+use tools::render::{self, Image, Render, Rgb};
+use tools::{Direction, Solution};
+use tools::Direction::{Down, Left, Right, Up};
+
+use crate::custom_error::AocError;
+
+pub mod custom_error;
+
+type Parsed = Instructions;
+type AocResult = u64;
+type AocResult2 = AocResult;
+type Int = i64;
+
+#[derive(Debug,PartialEq)]
+pub struct Color {
+    pub red:   u8,
+    pub green: u8,
+    pub blue:  u8,
+}
+
+fn from_hex(input: Span) -> Result<u64, std::num::ParseIntError> {
+    u64::from_str_radix(input.fragment(), 16)
+}
+
+fn is_hex_digit(c: char) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+fn hex_direction(input: Span) -> IResult<Direction> {
+    map_opt(one_of("0123"), Direction::from_hex_digit).context("hex direction").parse(input)
+}
+
+
+fn hex_distance(input: Span) -> IResult<u64> {
+    map_res(
+        take_while_m_n(5, 5, is_hex_digit),
+        from_hex
+    ).context("hex distance").parse(input)
+}
+
+fn hex_color(input: Span) -> IResult<AltInstruction> {
+    let (input, _) = tag("#")(input)?;
+    let (input, (distance, direction)) = tuple((hex_distance, hex_direction))(input)?;
+
+    Ok((input, AltInstruction{distance: distance as Int, direction}))
+}
+
+
+#[derive(Debug)]
+pub struct AltInstruction {
+    pub direction: Direction,
+    pub distance: Int,
+}
+
+impl AltInstruction {
+    pub fn deltas(&self) -> I64Vec2 {
+        match self.direction {
+            Up => I64Vec2::new(0, -self.distance),
+            Right => I64Vec2::new(self.distance, 0),
+            Down => I64Vec2::new(0, self.distance),
+            Left => I64Vec2::new(-self.distance, 0),
+        }
+    }
+}
+
+/// A line of the dig plan, read both ways.
+#[derive(Debug)]
+pub struct Instruction {
+    /// The direction and distance of part 1.
+    pub wrong: AltInstruction,
+    /// The instruction hidden in the colour, for part 2.
+    pub color: AltInstruction,
+}
+
+#[derive(Debug)]
+pub struct Instructions(pub Vec<Instruction>);
+
+fn instruction(input: Span) -> IResult<Instruction> {
+    let (input, direction) = map_opt(one_of("RLDU"), Direction::from_letter).context("direction").parse(input)?;
+    let (input, _) = space1(input)?;
+    let (input, distance) = complete::u8(input)?;
+    let (input, _) = space1(input)?;
+    let (input, color)= delimited(char('('), hex_color, char(')'))(input)?;
+
+    let wrong = AltInstruction{
+        direction,
+        distance: distance as Int,
+    };
+
+    Ok((input, Instruction{
+        wrong,
+        color,
+    }))
+}
+
+fn parse_instructions(input: Span) -> IResult<Instructions> {
+    parse::lines(cut(instruction)).map(Instructions).parse(input)
+}
+
+impl Instructions {
+    /// The trench dug by the instructions of part 1 if `wrong`, otherwise by those hidden in the colours.
+    pub fn polygon(&self, wrong: bool) -> Polygon {
+        Polygon::from_moves(self.0.iter().map(|instruction| {
+            let instruction = if wrong { &instruction.wrong } else { &instruction.color };
+            (instruction.direction, instruction.distance)
+        }))
+    }
+
+    /// Draws the trench of part 1, cropped to `size`.
+    pub fn draw(&self, size: IVec2) {
+        let mut position = IVec2::ZERO;
+        let mut trench = vec![position];
+        for instruction in self.0.iter() {
+            for _ in 0..instruction.wrong.distance {
+                position += instruction.wrong.direction.delta();
+                trench.push(position);
+            }
+        }
+
+        let min = trench.iter().copied().reduce(IVec2::min).unwrap();
+        let max = trench.iter().copied().reduce(IVec2::max).unwrap();
+        let mut grid = Grid::filled((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, false);
+        for position in trench {
+            grid[position - min] = true;
+        }
+
+        Render::new(&grid, |_, dug| if *dug { '█' } else { '·' })
+            .layer(render::Color::Red, |position| grid[position])
+            .viewport(IVec2::ZERO, size)
+            .print();
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        parse::finish(parse_instructions, content).map_err(|e| AocError::parse_error(content, e))
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:#?}", root);
+        Ok(root.polygon(true).dug_area() as AocResult)
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+        Ok(root.polygon(false).dug_area() as AocResult2)
+    }
+
+    /// The outline dug in part 1.
+    fn image(root: &Parsed) -> Option<Image> {
+        Some(Image::from_polygon(&root.polygon(true), Rgb::WHITE, Rgb::BLACK, Some(render::Color::Red.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::{input, Solution};
+
+    use crate::custom_error::AocError;
+    use crate::Day18;
+
+    #[test]
+    fn tests() -> miette::Result<()> {
+        let file = "sample.txt";
+        let content = input::load(file).expect("Cannot read file");
+        let root = Day18::parse(&content)?;
+        assert_eq!(62, Day18::part1(&root)?);
+        assert_eq!(952408144115, Day18::part2(&root)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        match Day18::parse("R 6 (#70c710)\nL 2 (#5713x0)\n") {
+            Err(AocError::ParseError { span, .. }) => assert_eq!(20, span.offset()),
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
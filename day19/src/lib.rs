@@ -0,0 +1,409 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::custom_error::AocError;
+use miette::Result;
+use tools::interval::Interval;
+use tools::parse::{self, IResult, Span};
+use tools::{Hyperbox, Solution};
+use nom::branch::alt;
+use nom::bytes::complete::is_not;
+use nom::character::complete;
+use nom::character::complete::{alpha1, char, multispace1, one_of};
+use nom::combinator::{all_consuming, cut, map_res};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, separated_pair};
+use nom::Parser;
+use nom_supreme::tag::complete::tag;
+use nom_supreme::ParserExt;
+
+pub mod custom_error;
+
+type Parsed = System<CATEGORIES>;
+type AocResult = Int;
+type AocResult2 = u128;
+type Int = i64;
+
+/// The number of rating categories in the puzzle, `xmas`.
+pub const CATEGORIES: usize = 4;
+
+/// A part's rating in each of `N` categories, indexed by axis.
+#[derive(Debug)]
+pub struct Part<const N: usize>(pub [Int; N]);
+
+/// Parts by their rating in each category.
+pub type PartRange<const N: usize> = Hyperbox<N>;
+
+impl<const N: usize> Part<N> {
+    pub fn score(&self) -> Int {
+        self.0.iter().sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum WorkflowTarget {
+    Accept,
+    Reject,
+    Goto(String),
+}
+
+/// Category names, numbered as axes in the order they first appear in the input.
+#[derive(Debug)]
+struct Categories {
+    names: RefCell<Vec<String>>,
+    limit: usize,
+}
+
+impl Categories {
+    fn axis(&self, name: &str) -> Result<usize, AocError> {
+        let mut names = self.names.borrow_mut();
+        if let Some(axis) = names.iter().position(|known| known == name) {
+            return Ok(axis);
+        }
+        if names.len() == self.limit {
+            return Err(AocError::Malformed(format!("more than {} categories", self.limit)));
+        }
+        names.push(name.to_string());
+        Ok(names.len() - 1)
+    }
+
+    /// A part's ratings by axis, if it rates every category exactly once.
+    fn ratings(&self, rated: Vec<(usize, Int)>) -> Result<Vec<Int>, AocError> {
+        let mut ratings = vec![None; self.limit];
+        for (axis, rating) in rated {
+            if ratings[axis].replace(rating).is_some() {
+                return Err(AocError::Malformed(format!("{} is rated twice", self.names.borrow()[axis])));
+            }
+        }
+        let count = ratings.iter().flatten().count();
+        if count != self.limit {
+            return Err(AocError::Malformed(format!("{} of {} categories are rated", count, self.limit)));
+        }
+        Ok(ratings.into_iter().flatten().collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkflowCondition {
+    pub less_than: bool,
+    /// The axis of the category in a [`PartRange`].
+    pub axis: usize,
+    pub value: Int,
+    pub target: WorkflowTarget,
+}
+
+#[derive(Debug, Clone)]
+pub struct Workflow {
+    pub name: String,
+    pub conditions: Vec<WorkflowCondition>,
+    /// Where parts go that match no condition.
+    pub target: WorkflowTarget,
+}
+
+#[derive(Debug)]
+pub struct System<const N: usize> {
+    /// The name of the category on every axis.
+    pub categories: Vec<String>,
+    pub workflows: HashMap<String, Workflow>,
+    pub parts: Vec<Part<N>>,
+}
+
+impl WorkflowCondition {
+    /// Where the part goes if it matches.
+    pub fn run<const N: usize>(&self, part: &Part<N>) -> Option<WorkflowTarget> {
+        let value = part.0[self.axis];
+        if self.less_than && value < self.value {
+            return Some(self.target.clone());
+        }
+        if !self.less_than && value > self.value {
+            return Some(self.target.clone());
+        }
+
+        None
+    }
+
+    /// Splits `range` into the parts that don't match and the matching ones with their target.
+    pub fn ranges<const N: usize>(&self, range: &PartRange<N>) -> (Option<PartRange<N>>, Option<(WorkflowTarget, PartRange<N>)>) {
+        let (modified, redirect) = if self.less_than {
+            let (below, above) = range.split(self.axis, self.value);
+            (above, below)
+        } else {
+            range.split(self.axis, self.value + 1)
+        };
+
+        (modified, redirect.map(|redirect| (self.target.clone(), redirect)))
+    }
+}
+
+impl Workflow {
+    pub fn run<const N: usize>(&self, part: &Part<N>) -> WorkflowTarget {
+        for condition in self.conditions.iter() {
+            if let Some(target) = condition.run(part) {
+                return target;
+            }
+        }
+
+        self.target.clone()
+    }
+}
+
+impl<const N: usize> System<N> {
+    /// Workflows and parts rating exactly `N` categories, with any names.
+    pub fn parse(content: &str) -> Result<Self, AocError> {
+        let categories = Categories { names: RefCell::default(), limit: N };
+        let (workflows, parts) = parse::finish(|input| parse_system(input, &categories), content)
+            .map_err(|e| AocError::parse_error(content, e))?;
+        let categories = categories.names.into_inner();
+        if categories.len() != N {
+            return Err(AocError::Malformed(format!("expected {} categories, found {}", N, categories.len())));
+        }
+
+        let workflows = workflows.into_iter().map(|workflow| (workflow.name.clone(), workflow)).collect();
+        let parts = parts.into_iter()
+            .map(|ratings| Part(ratings.try_into().expect("a rating per category")))
+            .collect();
+
+        Ok(System { categories, workflows, parts })
+    }
+
+    pub fn workflow(&self, name: &str) -> Result<&Workflow, AocError> {
+        self.workflows.get(name).ok_or_else(|| AocError::UnknownWorkflow(name.to_string()))
+    }
+
+    /// Whether the part is accepted, starting at the `in` workflow. Workflows sending parts in circles have no
+    /// solution.
+    pub fn check_part(&self, part: &Part<N>) -> Result<bool, AocError> {
+        let mut workflow = self.workflow("in")?;
+
+        for _ in 0..=self.workflows.len() {
+            let target = workflow.run(part);
+            match target {
+                WorkflowTarget::Accept => {
+                    return Ok(true);
+                }
+                WorkflowTarget::Reject => {
+                    return Ok(false);
+                }
+                WorkflowTarget::Goto(new_workflow) => {
+                    workflow = self.workflow(&new_workflow)?;
+                }
+            }
+        }
+
+        Err(AocError::NoSolution)
+    }
+
+    /// The summed ratings of the accepted parts.
+    pub fn score(&self) -> Result<Int, AocError> {
+        let mut score = 0;
+        for part in self.parts.iter() {
+            if self.check_part(part)? {
+                score += part.score();
+            }
+        }
+        Ok(score)
+    }
+
+    /// The parts of `range` that end up accepted when starting at `workflow`.
+    pub fn run_though_workflow(&self, workflow: &str, range: PartRange<N>) -> Result<Vec<PartRange<N>>, AocError> {
+        self.run_though_workflow_at(workflow, range, 0)
+    }
+
+    /// Passing through more workflows than there are means going in circles.
+    fn run_though_workflow_at(&self, workflow: &str, range: PartRange<N>, depth: usize) -> Result<Vec<PartRange<N>>, AocError> {
+        if depth > self.workflows.len() {
+            return Err(AocError::NoSolution);
+        }
+
+        let workflow = self.workflow(workflow)?;
+        let mut ranges = Vec::new();
+        let mut range = range;
+        for condition in workflow.conditions.iter() {
+            let (new_range, redirect) = condition.ranges(&range);
+            if let Some((target, target_range)) = redirect {
+                match target {
+                    WorkflowTarget::Accept => {
+                        ranges.push(target_range);
+                    }
+                    WorkflowTarget::Reject => {
+                        // Pass
+                    }
+                    WorkflowTarget::Goto(next_workflow) => {
+                        let mut new = self.run_though_workflow_at(&next_workflow, target_range, depth + 1)?;
+                        ranges.append(&mut new);
+                    }
+                }
+            }
+            let Some(new_range) = new_range else {
+                return Ok(ranges);
+            };
+            range = new_range;
+        }
+
+        match &workflow.target {
+            WorkflowTarget::Accept => {
+                ranges.push(range);
+            }
+            WorkflowTarget::Reject => {
+                // Pass
+            }
+            WorkflowTarget::Goto(next_workflow) => {
+                let mut new = self.run_though_workflow_at(next_workflow, range, depth + 1)?;
+                ranges.append(&mut new);
+            }
+        }
+
+        Ok(ranges)
+    }
+
+    /// The number of accepted parts with every rating in `ratings`.
+    pub fn ranges(&self, ratings: Interval<Int>) -> Result<AocResult2, AocError> {
+        let range = PartRange::cube(ratings);
+
+        let ranges = self.run_though_workflow("in", range)?;
+
+        //println!("{:#?}", ranges);
+
+        ranges.iter()
+            .try_fold(0u128, |count, range| count.checked_add(range.volume()?))
+            .ok_or_else(|| AocError::Malformed("too many parts to count".to_string()))
+    }
+}
+
+/// A category name, as its axis.
+fn category<'a>(categories: &Categories) -> impl FnMut(Span<'a>) -> IResult<'a, usize> + '_ {
+    move |input| cut(map_res(alpha1.context("category"), |name: Span| categories.axis(name.fragment())))(input)
+}
+
+/// `{x=787,m=2655,a=1222,s=2876}`, as ratings by axis.
+fn part<'a>(input: Span<'a>, categories: &Categories) -> IResult<'a, Vec<Int>> {
+    let ratings = delimited(char('{'), parse::record(category(categories), parse::integer), char('}'));
+    map_res(ratings, |rated| categories.ratings(rated))(input)
+}
+
+fn target(input: Span) -> IResult<WorkflowTarget> {
+    let (input, parsed) = alt((tag("A"), tag("R"), alpha1)).context("target").parse(input)?;
+    Ok((input, match *parsed.fragment() {
+        "A" => WorkflowTarget::Accept,
+        "R" => WorkflowTarget::Reject,
+        workflow => WorkflowTarget::Goto(workflow.to_string()),
+    }))
+}
+
+/// `a<2006:qkq`
+fn condition<'a>(input: Span<'a>, categories: &Categories) -> IResult<'a, WorkflowCondition> {
+    let (input, axis) = category(categories)(input)?;
+    let (input, comparator) = one_of("<>").context("comparator").parse(input)?;
+    let (input, value) = complete::i64(input)?;
+    let (input, _) = tag(":")(input)?;
+    let (input, target) = target(input)?;
+    Ok((input, WorkflowCondition { axis, target, value, less_than: comparator == '<' }))
+}
+
+fn conditions(input: Span) -> IResult<Vec<Span>> {
+    separated_list1(char(','), is_not(",}"))(input)
+}
+
+/// `px{a<2006:qkq,m>2090:A,rfg}`
+fn workflow<'a>(input: Span<'a>, categories: &Categories) -> IResult<'a, Workflow> {
+    let (input, name) = alpha1(input)?;
+    let (input, mut steps) = delimited(char('{'), conditions, char('}'))(input)?;
+
+    let mut conditions = Vec::new();
+
+    let last = steps.pop().unwrap();
+    let (_, target) = cut(all_consuming(target))(last)?;
+    for step in steps {
+        let (_, condition) = cut(all_consuming(|input| condition(input, categories)))(step)?;
+        conditions.push(condition);
+    }
+
+    Ok((input, Workflow { name: name.to_string(), conditions, target }))
+}
+
+/// The workflows, a blank line and the parts.
+type RawSystem = (Vec<Workflow>, Vec<Vec<Int>>);
+
+fn parse_system<'a>(input: Span<'a>, categories: &Categories) -> IResult<'a, RawSystem> {
+    let workflows = parse::lines(cut(|input| workflow(input, categories)));
+    let parts = parse::lines(cut(|input| part(input, categories)));
+    separated_pair(workflows, multispace1, parts)(input)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Parsed = Parsed;
+    type Answer1 = AocResult;
+    type Answer2 = AocResult2;
+    type Error = AocError;
+
+    fn parse(content: &str) -> Result<Parsed, AocError> {
+        System::parse(content)
+    }
+
+    fn part1(root: &Parsed) -> Result<AocResult, AocError> {
+        //println!("{:#?}", root);
+
+        root.score()
+    }
+
+    fn part2(root: &Parsed) -> Result<AocResult2, AocError> {
+
+        root.ranges(Interval::new(1, 4001))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tools::interval::Interval;
+    use tools::{input, Solution};
+
+    use crate::custom_error::AocError;
+    use crate::{Day19, System};
+
+    #[test]
+    fn tests() -> miette::Result<()> {
+        let file = "sample.txt";
+        let content = input::load(file).expect("Cannot read file");
+        let root = Day19::parse(&content)?;
+        assert_eq!(19114, Day19::part1(&root)?, "Part 1");
+        assert_eq!(167409079868000, Day19::part2(&root)?, "Part 2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error() {
+        match Day19::parse("in{x<5:A,R}\nqs{q>3:A,R}\n\n{x=1,m=2,a=3,s=4}\n") {
+            Err(AocError::ParseError { span, message, .. }) => {
+                assert_eq!((38, "Malformed input: more than 4 categories"), (span.offset(), message.as_str()))
+            }
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn any_categories() -> miette::Result<()> {
+        let system = System::<2>::parse("in{speed>10:A,cool<5:R,A}\n\n{speed=12,cool=1}\n{cool=2,speed=3}\n{speed=3,cool=9}\n")?;
+        assert_eq!(vec!["speed", "cool"], system.categories);
+        assert_eq!(25, system.score()?);
+        assert_eq!(360, system.ranges(Interval::new(1, 21))?);
+
+        assert!(matches!(System::<3>::parse("in{a<1:A,R}\n\n{a=1,b=2}\n"), Err(AocError::ParseError { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn unrated_categories() {
+        let error = |content: &str| match System::<2>::parse(content) {
+            Err(AocError::ParseError { span, message, .. }) => (span.offset(), message),
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        };
+
+        let workflows = "in{a<5:A,b>2:R,A}\n\n{a=1,b=2}\n";
+        assert_eq!((29, "Malformed input: 1 of 2 categories are rated".to_string()), error(&format!("{}{{b=4}}\n", workflows)));
+        assert_eq!((29, "Malformed input: a is rated twice".to_string()), error(&format!("{}{{a=1,b=2,a=3}}\n", workflows)));
+    }
+}
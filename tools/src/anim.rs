@@ -0,0 +1,137 @@
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::grid::Grid;
+use crate::render::{self, Color, Image, Render, Rgb, Style, Styled};
+
+/// One drawn state of a simulation.
+pub type Frame = Grid<Styled>;
+
+/// Frames pushed by a simulation, to replay or save afterwards. Stops taking frames after `limit` of them.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    limit: usize,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Starts recording the frames this thread pushes through [`frame`].
+pub fn start(limit: usize) {
+    RECORDER.with(|recorder| *recorder.borrow_mut() = Some(Recorder::new(limit)));
+}
+
+/// Stops recording and hands over what was recorded since [`start`].
+pub fn stop() -> Recorder {
+    RECORDER.with(|recorder| recorder.borrow_mut().take()).unwrap_or_default()
+}
+
+/// Hook for simulations: records the render while a recording runs, otherwise `render` isn't even called.
+pub fn frame<'a, T: 'a>(render: impl FnOnce() -> Render<'a, T>) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut().filter(|recorder| !recorder.is_full()) {
+            recorder.push(render().cells());
+        }
+    });
+}
+
+impl Recorder {
+    pub fn new(limit: usize) -> Self {
+        Recorder { frames: Vec::new(), limit }
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        if !self.is_full() {
+            self.frames.push(frame);
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.frames.len() >= self.limit
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Replays the frames on stdout at `fps` frames per second, redrawing in place on a terminal.
+    pub fn play(&self, fps: u32) {
+        let style = Style::detect();
+        let delay = Duration::from_secs_f64(1.0 / fps.max(1) as f64);
+        for (index, frame) in self.frames.iter().enumerate() {
+            if style == Style::Fancy {
+                print!("\x1b[2J\x1b[H");
+            }
+            println!("Frame {}/{}", index + 1, self.frames.len());
+            print!("{}", render::format(frame, style));
+            thread::sleep(delay);
+        }
+    }
+
+    /// A frame as a picture: coloured cells in their colour, other non-blank glyphs in grey.
+    fn image(frame: &Frame) -> Image {
+        Image::from_grid(frame, Rgb::BLACK, |_, (glyph, color)| match color {
+            Some(color) => Some((*color).into()),
+            None if !matches!(glyph, ' ' | '.' | '·') => Some(Color::Gray.into()),
+            None => None,
+        })
+    }
+
+    /// Writes the frames to `directory` as `frame0000.ppm`, `frame0001.ppm`, …
+    pub fn save_ppm(&self, directory: impl AsRef<Path>, size: u32) -> io::Result<()> {
+        fs::create_dir_all(&directory)?;
+        for (index, frame) in self.frames.iter().enumerate() {
+            let path = directory.as_ref().join(format!("frame{:04}.ppm", index));
+            fs::write(path, Recorder::image(frame).to_ppm(size))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the frames to `directory` as `frame0000.svg`, `frame0001.svg`, …
+    pub fn save_svg(&self, directory: impl AsRef<Path>) -> io::Result<()> {
+        fs::create_dir_all(&directory)?;
+        for (index, frame) in self.frames.iter().enumerate() {
+            let path = directory.as_ref().join(format!("frame{:04}.svg", index));
+            fs::write(path, Recorder::image(frame).to_svg())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+
+    use super::*;
+
+    #[test]
+    fn recording() {
+        let grid = Grid::chars("ab\ncd\n");
+        frame(|| Render::new(&grid, |_, c| *c));
+        assert!(stop().is_empty(), "nothing is recorded before start");
+
+        start(2);
+        for highlighted in 0..3 {
+            frame(|| Render::new(&grid, |_, c| *c).highlight(Color::Red, [IVec2::new(highlighted, 0)]));
+        }
+        let recorder = stop();
+        assert_eq!(2, recorder.len());
+        assert_eq!(('b', Some(Color::Red)), recorder.frames()[1][IVec2::new(1, 0)]);
+        assert_eq!(('b', None), recorder.frames()[0][IVec2::new(1, 0)]);
+        assert!(stop().is_empty());
+    }
+}
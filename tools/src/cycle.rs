@@ -0,0 +1,77 @@
+/// Where the sequence `initial, step(initial), step(step(initial)), …` starts repeating itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The index of the first state that is part of the cycle.
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The index within the first pass through the cycle whose state equals the one at `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start { n } else { self.start + (n - self.start) % self.length }
+    }
+}
+
+fn advance<S>(mut state: S, steps: usize, step: &mut impl FnMut(&S) -> S) -> S {
+    for _ in 0..steps {
+        state = step(&state);
+    }
+    state
+}
+
+/// Brent's algorithm. Only terminates if the sequence repeats, e.g. because there are finitely many states.
+pub fn find<S: Clone + Eq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = advance(initial.clone(), length, &mut step);
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, without taking more than the steps to reach the cycle and go around it twice.
+pub fn nth<S: Clone + Eq>(initial: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = find(initial, &mut step);
+    advance(initial.clone(), cycle.equivalent(n), &mut step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rho() {
+        // 0 1 2 3 4 5 3 4 5 …
+        let step = |n: &u32| if *n == 5 { 3 } else { n + 1 };
+        assert_eq!(Cycle { start: 3, length: 3 }, find(&0, step));
+        assert_eq!(2, nth(&0, step, 2));
+        assert_eq!(4, nth(&0, step, 1_000_000_000));
+        assert_eq!(Cycle { start: 0, length: 1 }, find(&7, |n: &u32| *n));
+    }
+
+    #[test]
+    fn pure_cycle() {
+        let step = |n: &u64| (n * 3) % 7;
+        assert_eq!(Cycle { start: 0, length: 6 }, find(&1, step));
+        assert_eq!(3, nth(&1, step, 6 * 1000 + 1));
+    }
+}
@@ -0,0 +1,205 @@
+use enumset::EnumSetType;
+use glam::IVec2;
+
+use crate::Direction::{Down, Left, Right, Up};
+
+/// One of the four orthogonal directions on a grid whose `y` grows downwards.
+#[derive(Debug, Hash, EnumSetType)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All four, clockwise starting with [`Up`].
+    pub fn all() -> impl Iterator<Item = Direction> {
+        [Up, Right, Down, Left].into_iter()
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Up => Down,
+            Right => Left,
+            Down => Up,
+            Left => Right,
+        }
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Up => Left,
+            Right => Up,
+            Down => Right,
+            Left => Down,
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn delta(&self) -> IVec2 {
+        match self {
+            Up => IVec2::new(0, -1),
+            Right => IVec2::new(1, 0),
+            Down => IVec2::new(0, 1),
+            Left => IVec2::new(-1, 0),
+        }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Up | Down)
+    }
+
+    /// `U`, `R`, `D` or `L`.
+    pub fn from_letter(c: char) -> Option<Direction> {
+        match c {
+            'U' => Some(Up),
+            'R' => Some(Right),
+            'D' => Some(Down),
+            'L' => Some(Left),
+            _ => None,
+        }
+    }
+
+    /// `0` is right, then clockwise: `1` down, `2` left and `3` up.
+    pub fn from_hex_digit(c: char) -> Option<Direction> {
+        match c {
+            '0' => Some(Right),
+            '1' => Some(Down),
+            '2' => Some(Left),
+            '3' => Some(Up),
+            _ => None,
+        }
+    }
+}
+
+/// A direction including the diagonals, for puzzles that move like a king.
+#[derive(Debug, Hash, EnumSetType)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    const CLOCKWISE: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// All eight, clockwise starting with [`Direction8::Up`].
+    pub fn all() -> impl Iterator<Item = Direction8> {
+        Self::CLOCKWISE.into_iter()
+    }
+
+    fn turned(&self, eighths: usize) -> Direction8 {
+        Self::CLOCKWISE[(*self as usize + eighths) % 8]
+    }
+
+    pub fn opposite(&self) -> Direction8 {
+        self.turned(4)
+    }
+
+    /// An eighth turn counterclockwise.
+    pub fn turn_left(&self) -> Direction8 {
+        self.turned(7)
+    }
+
+    /// An eighth turn clockwise.
+    pub fn turn_right(&self) -> Direction8 {
+        self.turned(1)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    pub fn delta(&self) -> IVec2 {
+        match self {
+            Direction8::Up => IVec2::new(0, -1),
+            Direction8::UpRight => IVec2::new(1, -1),
+            Direction8::Right => IVec2::new(1, 0),
+            Direction8::DownRight => IVec2::new(1, 1),
+            Direction8::Down => IVec2::new(0, 1),
+            Direction8::DownLeft => IVec2::new(-1, 1),
+            Direction8::Left => IVec2::new(-1, 0),
+            Direction8::UpLeft => IVec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Up => Direction8::Up,
+            Right => Direction8::Right,
+            Down => Direction8::Down,
+            Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use enumset::EnumSet;
+
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for direction in Direction::all() {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+            assert_eq!(direction.delta().perp(), direction.turn_right().delta());
+        }
+        for direction in Direction8::all() {
+            assert_eq!(direction.opposite(), direction.turn_left().turn_left().turn_left().turn_left());
+            assert_eq!(direction.delta(), -direction.opposite().delta());
+        }
+        assert_eq!(Direction8::UpLeft, Direction8::Up.turn_left());
+    }
+
+    #[test]
+    fn opposite() {
+        assert_eq!(Up.opposite(), Down);
+        assert_eq!(Down.opposite(), Up);
+        assert_eq!(Left.opposite(), Right);
+        assert_eq!(Right.opposite(), Left);
+    }
+
+    #[test]
+    fn encodings() {
+        assert_eq!(Some(Right), Direction::from_letter('R'));
+        assert_eq!(Some(Up), Direction::from_hex_digit('3'));
+        assert_eq!(None, Direction::from_hex_digit('4'));
+    }
+
+    #[test]
+    fn sets() {
+        let mut seen: EnumSet<Direction> = EnumSet::new();
+        assert!(seen.insert(Up));
+        assert!(!seen.insert(Up));
+        assert_eq!(EnumSet::all(), Direction::all().collect::<EnumSet<_>>());
+    }
+}
@@ -0,0 +1,98 @@
+use glam::IVec2;
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+use crate::parse;
+
+/// What can go wrong solving a day, from unreadable input to a puzzle without answer.
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+
+    #[error("Cannot parse input")]
+    #[diagnostic(code(aoc::parse_error))]
+    ParseError {
+        #[source_code]
+        input: String,
+        #[label("{message}")]
+        span: SourceSpan,
+        message: String,
+    },
+
+    /// `line` counts from 1.
+    #[error("Invalid line {line}: {message}")]
+    #[diagnostic(code(aoc::invalid_line))]
+    InvalidLine { line: usize, message: String },
+
+    #[error("Unexpected {ch:?} at {pos}")]
+    #[diagnostic(code(aoc::invalid_cell))]
+    InvalidCell { pos: IVec2, ch: char },
+
+    #[error("Malformed input: {0}")]
+    #[diagnostic(code(aoc::malformed))]
+    Malformed(String),
+
+    #[error("The input has no start")]
+    #[diagnostic(code(aoc::missing_start))]
+    MissingStart,
+
+    #[error("No workflow is named {0:?}")]
+    #[diagnostic(code(aoc::unknown_workflow))]
+    UnknownWorkflow(String),
+
+    #[error("No node is named {0:?}")]
+    #[diagnostic(code(aoc::unknown_node))]
+    UnknownNode(String),
+
+    #[error("The puzzle has no solution")]
+    #[diagnostic(code(aoc::no_solution))]
+    NoSolution,
+}
+
+impl AocError {
+    /// Points at the place in `input` where the parser got stuck.
+    pub fn parse_error(input: &str, error: nom::Err<parse::Error>) -> AocError {
+        let (span, message) = parse::locate(input, error);
+        AocError::ParseError { input: input.to_string(), span, message }
+    }
+
+    /// Points at `length` bytes from `offset` in `input`, for input that parses but makes no sense.
+    pub fn located(input: &str, offset: usize, length: usize, message: impl Into<String>) -> AocError {
+        AocError::ParseError { input: input.to_string(), span: (offset, length).into(), message: message.into() }
+    }
+
+    /// Points an [`AocError::InvalidLine`] or [`AocError::InvalidCell`] found in `part`, a slice of `input` such as one
+    /// of its [`input::blocks`](crate::input::blocks), at the same place in the whole `input`. Other errors are kept.
+    pub fn within(self, input: &str, part: &str) -> AocError {
+        let start = part.as_ptr() as usize - input.as_ptr() as usize;
+        debug_assert!(start + part.len() <= input.len(), "part is not a slice of input");
+        // Where the line at `index` starts in `input`, and its text.
+        let line = |index: usize| {
+            let mut offset = start;
+            for line in part.split_inclusive('\n').take(index) {
+                offset += line.len();
+            }
+            (offset, input[offset..].lines().next().unwrap_or(""))
+        };
+
+        match self {
+            AocError::InvalidLine { line: number, message } => {
+                let (offset, text) = line(number - 1);
+                AocError::located(input, offset, text.len(), message)
+            }
+            AocError::InvalidCell { pos, ch } => {
+                let (offset, text) = line(pos.y as usize);
+                let column = text.char_indices().nth(pos.x as usize).map_or(text.len(), |(column, _)| column);
+                AocError::located(input, offset + column, ch.len_utf8(), format!("unexpected {:?}", ch))
+            }
+            error => error,
+        }
+    }
+
+    /// An [`AocError::InvalidLine`] for the line at `index`, counting from 0.
+    pub fn invalid_line(index: usize, message: impl Into<String>) -> AocError {
+        AocError::InvalidLine { line: index + 1, message: message.into() }
+    }
+}
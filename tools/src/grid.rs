@@ -0,0 +1,248 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use glam::IVec2;
+
+use crate::{input, AocError};
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+const ORTHOGONAL: [IVec2; 4] = [IVec2::new(0, -1), IVec2::new(1, 0), IVec2::new(0, 1), IVec2::new(-1, 0)];
+
+/// Offsets of all eight neighbours, clockwise starting at the top left.
+const SURROUNDING: [IVec2; 8] = [
+    IVec2::new(-1, -1),
+    IVec2::new(0, -1),
+    IVec2::new(1, -1),
+    IVec2::new(1, 0),
+    IVec2::new(1, 1),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 1),
+    IVec2::new(-1, 0),
+];
+
+/// A dense, rectangular 2D grid stored row by row. `x` grows to the right, `y` downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `cells` doesn't hold exactly `width * height` values.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "grid of {}x{} needs {} cells", width, height, width * height);
+        Grid { width, height, cells }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one cell per character, one row per line. Lines differing in length from the first are an
+    /// [`AocError::InvalidLine`].
+    pub fn parse<E: From<AocError>>(content: &str, mut cell: impl FnMut(IVec2, char) -> Result<T, E>) -> Result<Self, E> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (y, line) in input::lines(content).enumerate() {
+            let row: Result<Vec<T>, E> = line.chars()
+                .enumerate()
+                .map(|(x, c)| cell(IVec2::new(x as i32, y as i32), c))
+                .collect();
+            let row = row?;
+            if let Some(first) = rows.first().filter(|first| first.len() != row.len()) {
+                return Err(AocError::invalid_line(y, format!("expected {} cells, found {}", first.len(), row.len())).into());
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from(rows))
+    }
+
+    /// [`Grid::parse`] into `Option<T>` where `empty` is `None` and every other character must turn into a cell.
+    pub fn parse_sparse(content: &str, empty: char, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<Option<T>>, AocError> {
+        Grid::parse(content, |pos, ch| match ch {
+            ch if ch == empty => Ok(None),
+            ch => cell(ch).map(Some).ok_or(AocError::InvalidCell { pos, ch }),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Width and height as a vector.
+    pub fn size(&self) -> IVec2 {
+        IVec2::new(self.width as i32, self.height as i32)
+    }
+
+    pub fn contains(&self, position: IVec2) -> bool {
+        position.x >= 0 && position.y >= 0 && (position.x as usize) < self.width && (position.y as usize) < self.height
+    }
+
+    fn index_of(&self, position: IVec2) -> Option<usize> {
+        self.contains(position).then(|| position.y as usize * self.width + position.x as usize)
+    }
+
+    pub fn get(&self, position: IVec2) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: IVec2) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| IVec2::new((index % width) as i32, (index / width) as i32))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row by row, whose cell matches.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<IVec2> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    /// The up to four orthogonal neighbours of `position` inside the grid.
+    pub fn neighbours4(&self, position: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        ORTHOGONAL.iter().map(move |delta| position + *delta).filter(|p| self.contains(*p))
+    }
+
+    /// The up to eight neighbours of `position` inside the grid, diagonals included.
+    pub fn neighbours8(&self, position: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        SURROUNDING.iter().map(move |delta| position + *delta).filter(|p| self.contains(*p))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Mirrored along the main diagonal, so rows become columns.
+    pub fn transposed(&self) -> Grid<T> where T: Clone {
+        let cells = (0..self.width).flat_map(|x| self.column(x).cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Turned a quarter clockwise: the first column, read bottom up, becomes the first row.
+    pub fn rotated_clockwise(&self) -> Grid<T> where T: Clone {
+        let cells = (0..self.width).flat_map(|x| self.column(x).rev().cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Turned a quarter counterclockwise: the last column, read top down, becomes the first row.
+    pub fn rotated_counterclockwise(&self) -> Grid<T> where T: Clone {
+        let cells = (0..self.width).rev().flat_map(|x| self.column(x).cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+impl Grid<char> {
+    /// The characters of the input as they are.
+    pub fn chars(content: &str) -> Self {
+        Grid::from(input::grid(content))
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    /// Panics if the rows differ in length.
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "rows of a grid must have the same length");
+        Grid { width, height, cells: rows.into_iter().flatten().collect() }
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: IVec2) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("{} is outside of the {}x{} grid", position, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, position: IVec2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| panic!("{} is outside of the {}x{} grid", position, width, height))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::chars("abc\ndef\n")
+    }
+
+    #[test]
+    fn access() {
+        let grid = sample();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&'f'), grid.get(IVec2::new(2, 1)));
+        assert_eq!(None, grid.get(IVec2::new(3, 0)));
+        assert_eq!(None, grid.get(IVec2::new(0, -1)));
+        assert_eq!(vec!['b', 'e'], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!("abc\ndef\n", grid.to_string());
+        let digit = |pos, ch: char| ch.to_digit(10).ok_or(AocError::InvalidCell { pos, ch });
+        let parsed: Result<Grid<u32>, AocError> = Grid::parse("12\n3x", digit);
+        assert!(matches!(parsed, Err(AocError::InvalidCell { pos, ch: 'x' }) if pos == IVec2::new(1, 1)));
+        assert!(matches!(Grid::parse("12\n3", digit), Err(AocError::InvalidLine { line: 2, .. })));
+        let sparse = Grid::parse_sparse("#.\n.#", '.', |ch| (ch == '#').then_some(ch)).unwrap();
+        assert_eq!(vec![Some('#'), None], sparse.row(0).to_vec());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = sample();
+        assert_eq!(3, grid.neighbours4(IVec2::new(1, 0)).count());
+        assert_eq!(3, grid.neighbours8(IVec2::ZERO).count());
+        assert_eq!(5, grid.neighbours8(IVec2::new(1, 1)).count());
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = sample();
+        assert_eq!("ad\nbe\ncf\n", grid.transposed().to_string());
+        assert_eq!("da\neb\nfc\n", grid.rotated_clockwise().to_string());
+        assert_eq!("cf\nbe\nad\n", grid.rotated_counterclockwise().to_string());
+        assert_eq!(grid, grid.rotated_clockwise().rotated_counterclockwise());
+    }
+}
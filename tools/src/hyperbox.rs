@@ -0,0 +1,78 @@
+use crate::interval::Interval;
+
+/// An axis-aligned box in `N` dimensions: one half-open interval per axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hyperbox<const N: usize> {
+    axes: [Interval<i64>; N],
+}
+
+impl<const N: usize> Hyperbox<N> {
+    pub fn new(axes: [Interval<i64>; N]) -> Self {
+        Hyperbox { axes }
+    }
+
+    /// The same interval along every axis.
+    pub fn cube(interval: Interval<i64>) -> Self {
+        Hyperbox { axes: [interval; N] }
+    }
+
+    pub fn axis(&self, axis: usize) -> Interval<i64> {
+        self.axes[axis]
+    }
+
+    pub fn with_axis(&self, axis: usize, interval: Interval<i64>) -> Self {
+        let mut axes = self.axes;
+        axes[axis] = interval;
+        Hyperbox { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.axes.iter().zip(point).all(|(interval, value)| interval.contains(value))
+    }
+
+    /// The part with `axis` below `at` and the part from `at` on, each `None` when empty.
+    pub fn split(&self, axis: usize, at: i64) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.axes[axis].split_at(at);
+        (below.map(|below| self.with_axis(axis, below)), above.map(|above| self.with_axis(axis, above)))
+    }
+
+    /// The number of integer points inside, `None` if that doesn't fit in a `u128`.
+    pub fn volume(&self) -> Option<u128> {
+        self.axes.iter().try_fold(1u128, |volume, interval| volume.checked_mul(interval.len() as u128))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        let cube = Hyperbox::<4>::cube(Interval::new(1, 4001));
+        assert_eq!(Some(256_000_000_000_000), cube.volume());
+
+        let (below, above) = cube.split(2, 1000);
+        let (below, above) = (below.unwrap(), above.unwrap());
+        assert_eq!(Interval::new(1, 1000), below.axis(2));
+        assert_eq!(Interval::new(1000, 4001), above.axis(2));
+        assert_eq!(cube.volume(), Some(below.volume().unwrap() + above.volume().unwrap()));
+        assert!(below.contains([1, 4000, 999, 1]) && !below.contains([1, 4000, 1000, 1]));
+        assert_eq!((None, Some(cube)), cube.split(0, -5));
+    }
+
+    #[test]
+    fn huge() {
+        let cube = Hyperbox::<3>::cube(Interval::new(-(1 << 40), 1 << 40));
+        assert_eq!(Some(1 << 123), cube.volume());
+
+        // 2^128 points is one too many.
+        let limit = Hyperbox::<4>::cube(Interval::new(0, 1 << 32));
+        assert_eq!(None, limit.volume());
+        assert_eq!(Some(u128::MAX - (1 << 96) + 1), limit.with_axis(0, Interval::new(1, 1 << 32)).volume());
+        assert!(Hyperbox::new([Interval::new(0, 5), Interval::new(3, 3)]).is_empty());
+    }
+}
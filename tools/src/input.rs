@@ -0,0 +1,75 @@
+//! Reading puzzle input without tripping over line endings.
+//!
+//! Files may come with Windows line endings or a trailing newline; [`load`] normalizes both, and
+//! the splitting helpers tolerate them as well so days also work on raw strings.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Reads a file with `\r\n` turned into `\n` and trailing newlines removed.
+pub fn load(path: impl AsRef<Path>) -> io::Result<String> {
+    Ok(normalize(&fs::read_to_string(path)?))
+}
+
+/// Turns `\r\n` into `\n` and removes trailing newlines.
+pub fn normalize(content: &str) -> String {
+    content.replace("\r\n", "\n").trim_end_matches('\n').to_string()
+}
+
+/// The lines of the input, without trailing empty lines.
+pub fn lines(content: &str) -> impl Iterator<Item = &str> {
+    content.trim_end_matches(['\r', '\n']).lines()
+}
+
+/// Parts of the input separated by one or more blank lines.
+///
+/// Blocks are slices of `content` without their final line break, so offsets into them can be
+/// mapped back to the whole input.
+pub fn blocks(content: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let text = line.trim_end_matches(['\r', '\n']);
+        if text.trim().is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(&content[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + text.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        blocks.push(&content[start..end]);
+    }
+
+    blocks
+}
+
+/// The characters of every line, row by row.
+pub fn grid(content: &str) -> Vec<Vec<char>> {
+    lines(content).map(|line| line.chars().collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings() {
+        assert_eq!("a\nb", normalize("a\r\nb\r\n\r\n"));
+        assert_eq!(vec!["a", "b"], lines("a\r\nb\r\n").collect::<Vec<_>>());
+        assert_eq!(vec!["a", "", "b"], lines("a\n\nb\n\n").collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn blocks_and_grid() {
+        assert_eq!(vec!["a\r\nb", "c"], blocks("a\r\nb\r\n\r\n\r\nc\r\n"));
+        assert_eq!(vec!["a", "b\nc"], blocks("\na\n\nb\nc"));
+        assert_eq!(vec![vec!['#', '.'], vec!['.', '#']], grid("#.\r\n.#\r\n"));
+    }
+}
@@ -0,0 +1,230 @@
+use std::cmp::{max, min};
+use std::fmt::{Debug, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, Range, Sub};
+
+/// The half-open range `start..end`. Empty when `end <= start`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// `length` values starting at `start`.
+    pub fn with_length(start: T, length: T) -> Self where T: Add<Output = T> {
+        Interval { start, end: start + length }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn len(&self) -> T where T: Sub<Output = T> + Default {
+        if self.is_empty() { T::default() } else { self.end - self.start }
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let overlap = Interval { start: max(self.start, other.start), end: min(self.end, other.end) };
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The values below `at` and the values from `at` on, each `None` when empty.
+    pub fn split_at(&self, at: T) -> (Option<Interval<T>>, Option<Interval<T>>) {
+        let below = Interval { start: self.start, end: min(self.end, at) };
+        let above = Interval { start: max(self.start, at), end: self.end };
+        ((!below.is_empty()).then_some(below), (!above.is_empty()).then_some(above))
+    }
+
+    /// Moved so that `from` ends up at `to`. Works for unsigned `T` as long as `from <= start`.
+    pub fn shift(&self, from: T, to: T) -> Interval<T> where T: Add<Output = T> + Sub<Output = T> {
+        Interval { start: self.start - from + to, end: self.end - from + to }
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval { start: range.start, end: range.end }
+    }
+}
+
+impl<T: Debug> Debug for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+/// A union of intervals, kept sorted, non-empty, and with overlapping or touching intervals merged.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    fn normalized(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by_key(|interval| interval.start);
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = max(last.end, interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals.iter().any(|interval| interval.contains(value))
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T where T: Sub<Output = T> + Default + Sum {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = IntervalSet::normalized(intervals);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut left, mut right) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        for interval in self.iter() {
+            let mut rest = Some(*interval);
+            for cut in other.iter() {
+                let Some(current) = rest else { break };
+                let (below, _) = current.split_at(cut.start);
+                let (_, above) = current.split_at(cut.end);
+                intervals.extend(below);
+                rest = above;
+            }
+            intervals.extend(rest);
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (IntervalSet<T>, IntervalSet<T>) {
+        let (below, above): (Vec<_>, Vec<_>) = self.iter().map(|interval| interval.split_at(at)).unzip();
+        (below.into_iter().flatten().collect(), above.into_iter().flatten().collect())
+    }
+
+    /// Every interval moved so that `from` ends up at `to`, see [`Interval::shift`].
+    pub fn shift(&self, from: T, to: T) -> IntervalSet<T> where T: Add<Output = T> + Sub<Output = T> {
+        self.iter().map(|interval| interval.shift(from, to)).collect()
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        IntervalSet::normalized(iter.into_iter().collect())
+    }
+}
+
+impl<T: Copy + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet::normalized(vec![interval])
+    }
+}
+
+impl<T: Debug> Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.intervals.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().map(Interval::from).collect()
+    }
+
+    #[test]
+    fn interval() {
+        let interval = Interval::with_length(5u64, 3);
+        assert_eq!(3, interval.len());
+        assert!(interval.contains(7) && !interval.contains(8));
+        assert_eq!((Some(Interval::new(5, 6)), Some(Interval::new(6, 8))), interval.split_at(6));
+        assert_eq!((None, Some(interval)), interval.split_at(2));
+        assert_eq!(Some(Interval::new(6, 8)), interval.intersection(&Interval::new(6, 20)));
+        assert_eq!(None, interval.intersection(&Interval::new(8, 20)));
+        assert_eq!(Interval::new(1, 4), interval.shift(5, 1));
+        assert_eq!(0, Interval::new(4u64, 2).len());
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(set(&[0..5, 7..9]), set(&[3..5, 7..8, 0..3, 8..9, 2..2]));
+        assert_eq!(7, set(&[0..5, 3..7, 10..10]).len());
+        let mut merged = set(&[0..2, 4..6]);
+        merged.insert(Interval::new(1, 5));
+        assert_eq!(IntervalSet::from(Interval::new(0, 6)), merged);
+    }
+
+    #[test]
+    fn algebra() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(IntervalSet::from(Interval::new(0, 40)), a.union(&b));
+        assert_eq!(set(&[5..10, 20..25, 28..30]), a.intersection(&b));
+        assert_eq!(set(&[0..5, 25..28]), a.difference(&b));
+        assert_eq!(set(&[10..20, 30..40]), b.difference(&a));
+        assert_eq!((set(&[0..10, 20..22]), IntervalSet::from(Interval::new(22, 30))), a.split_at(22));
+        assert_eq!(set(&[-10..0, 10..20]), a.shift(10, 0));
+        assert_eq!(Some(0), a.min());
+    }
+}
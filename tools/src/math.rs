@@ -0,0 +1,124 @@
+use num_traits::{PrimInt, Signed};
+
+fn abs<T: PrimInt>(value: T) -> T {
+    if value < T::zero() { T::zero() - value } else { value }
+}
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let (mut a, mut b) = (abs(a), abs(b));
+    while b != T::zero() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative. 0 if either number is 0.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    if a == T::zero() || b == T::zero() {
+        return T::zero();
+    }
+    abs(a / gcd(a, b) * b)
+}
+
+/// `(g, x, y)` with `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::one(), T::zero());
+    let (mut y0, mut y1) = (T::zero(), T::one());
+    while r1 != T::zero() {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::zero() { (-r0, -x0, -y0) } else { (r0, x0, y0) }
+}
+
+/// `a` reduced to `0..m`.
+fn modulo<T: PrimInt + Signed>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::zero() { r + m } else { r }
+}
+
+/// `a * b % m` for `a` and `b` in `0..m`, without overflowing even when `a * b` doesn't fit in `T`.
+fn mul_mod<T: PrimInt>(a: T, b: T, m: T) -> T {
+    if let Some(product) = a.checked_mul(&b) {
+        return product % m;
+    }
+    let add = |a: T, b: T| if a >= m - b { a - (m - b) } else { a + b };
+    let (mut a, mut b, mut product) = (a, b, T::zero());
+    while b > T::zero() {
+        if b & T::one() == T::one() {
+            product = add(product, a);
+        }
+        a = add(a, a);
+        b = b >> 1;
+    }
+    product
+}
+
+/// The `x` with `x ≡ residue (mod modulus)` for every given `(residue, modulus)`, as `(x, m)` with `0 <= x < m` and `m` the
+/// lcm of the moduli. The moduli need not be coprime; `None` if the congruences contradict each other or `m` doesn't fit
+/// in `T`.
+pub fn crt<T: PrimInt + Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences.into_iter().try_fold((T::zero(), T::one()), |(a1, m1), (a2, m2)| {
+        let (g, p, _) = extended_gcd(m1, m2);
+        let a2 = modulo(a2, m2);
+        let difference = a2 - a1;
+        if difference % g != T::zero() {
+            return None;
+        }
+        let modulus = (m1 / g).checked_mul(&m2)?;
+        let step = m2 / g;
+        let k = mul_mod(modulo(difference / g, step), modulo(p, step), step);
+        // `a1 < m1` and `k < step`, so this stays below `modulus`.
+        Some((a1 + m1 * k, modulus))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisors() {
+        assert_eq!(6, gcd(12u32, 18));
+        assert_eq!(6, gcd(-12i64, 18));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(36, lcm(12u128, 18));
+        assert_eq!(36, lcm(-12i32, 18));
+        assert_eq!(0, lcm(0, 7));
+        assert_eq!(60, [4u8, 6, 10].into_iter().reduce(lcm).unwrap());
+    }
+
+    #[test]
+    fn extended() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+        let (g, x, y) = extended_gcd(-7i32, 3);
+        assert_eq!((1, -7 * x + 3 * y), (g, 1));
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(Some((23, 105)), crt([(2i64, 3), (3, 5), (2, 7)]));
+        // Moduli sharing a factor.
+        assert_eq!(Some((10, 12)), crt([(4i64, 6), (2, 4)]));
+        assert_eq!(None, crt([(1i64, 6), (2, 4)]));
+        assert_eq!(Some((0, 1)), crt::<i32>([]));
+        assert_eq!(Some((4, 7)), crt([(-3i64, 7)]));
+    }
+
+    #[test]
+    fn chinese_remainder_large_moduli() {
+        let (p, q) = (3_000_000_019i64, 3_000_000_037);
+        assert_eq!(Some((6_520_576_253_420_439_465, p * q)), crt([(123_456_789, p), (q - 2, q)]));
+        assert_eq!(Some((p - 1, p * q)), crt([(p - 1, p), (p - 1, q)]));
+        // The lcm no longer fits in an i64.
+        assert_eq!(None, crt([(1, p), (2, q), (3, 7)]));
+        assert_eq!(i64::MAX - 2, mul_mod(i64::MAX - 1, 2, i64::MAX));
+        assert_eq!(1, mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX));
+    }
+}
@@ -0,0 +1,190 @@
+//! Nom parsers for the shapes puzzle input keeps coming in: number lists, `label: values` lines, blocks separated by
+//! blank lines, grids of characters, `#rrggbb` colours and `key=value` records.
+//!
+//! They all parse located spans into nom-supreme's error tree, so a failure knows where it happened: [`finish`] runs a
+//! parser over a whole input and [`locate`] turns its error into a span and a message for a diagnostic.
+
+use std::str::FromStr;
+
+use miette::SourceSpan;
+use nom::branch::alt;
+use nom::bytes::complete::take_while_m_n;
+use nom::character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1};
+use nom::combinator::{all_consuming, eof, map_res, not, opt, recognize, verify};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{pair, preceded, separated_pair, terminated, tuple};
+use nom::Parser;
+use nom_locate::LocatedSpan;
+use nom_supreme::error::{BaseErrorKind, ErrorTree, StackContext};
+use nom_supreme::ParserExt;
+
+use crate::grid::Grid;
+use crate::render::Rgb;
+
+pub type Span<'a> = LocatedSpan<&'a str>;
+pub type Error<'a> = ErrorTree<Span<'a>>;
+pub type IResult<'a, T> = nom::IResult<Span<'a>, T, Error<'a>>;
+
+/// A decimal integer with an optional sign, as any type that parses from a string.
+pub fn integer<'a, T>(input: Span<'a>) -> IResult<'a, T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), |digits: Span| digits.fragment().parse::<T>())
+        .context("integer")
+        .parse(input)
+}
+
+/// Integers separated by spaces, like `79 14  55 13`.
+pub fn integers<'a, T>(input: Span<'a>) -> IResult<'a, Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    separated_list1(space1, integer)(input)
+}
+
+/// One or more `item`s separated by commas, with optional spaces around them.
+pub fn comma_separated<'a, T>(item: impl Parser<Span<'a>, T, Error<'a>>) -> impl FnMut(Span<'a>) -> IResult<'a, Vec<T>> {
+    separated_list1(tuple((space0, char(','), space0)), item)
+}
+
+/// `label: values`, like `Card 1: 41 48 | 83 86` or `seeds: 79 14 55 13`.
+pub fn labeled<'a, L, T>(
+    label: impl Parser<Span<'a>, L, Error<'a>>,
+    values: impl Parser<Span<'a>, T, Error<'a>>,
+) -> impl FnMut(Span<'a>) -> IResult<'a, (L, T)> {
+    separated_pair(label, pair(char(':'), space0), values)
+}
+
+/// One `item` per line. Stops before a blank line or a final line break, so items may `cut` and still sit in
+/// [`blocks`].
+pub fn lines<'a, T>(item: impl Parser<Span<'a>, T, Error<'a>>) -> impl FnMut(Span<'a>) -> IResult<'a, Vec<T>> {
+    separated_list1(terminated(line_ending, not(alt((line_ending, eof)))), item)
+}
+
+/// Blocks separated by one or more blank lines.
+pub fn blocks<'a, T>(block: impl Parser<Span<'a>, T, Error<'a>>) -> impl FnMut(Span<'a>) -> IResult<'a, Vec<T>> {
+    separated_list1(tuple((line_ending, many1(line_ending), not(eof))), block)
+}
+
+/// Lines of cells, all equally long. `cell` never sees a line break.
+pub fn grid<'a, T>(cell: impl Parser<Span<'a>, T, Error<'a>>) -> impl FnMut(Span<'a>) -> IResult<'a, Grid<T>> {
+    let mut rows = verify(lines(many1(preceded(not(line_ending), cell))), |rows: &Vec<Vec<T>>| {
+        rows.iter().all(|row| row.len() == rows[0].len())
+    }).context("rectangular grid");
+
+    move |input| {
+        let (input, rows) = rows.parse(input)?;
+        let (width, height) = (rows[0].len(), rows.len());
+        Ok((input, Grid::new(width, height, rows.into_iter().flatten().collect())))
+    }
+}
+
+fn hex_byte(input: Span) -> IResult<u8> {
+    map_res(take_while_m_n(2, 2, |c: char| c.is_ascii_hexdigit()), |hex: Span| u8::from_str_radix(hex.fragment(), 16))(input)
+}
+
+/// A colour written as `#rrggbb`.
+pub fn hex_color(input: Span) -> IResult<Rgb> {
+    preceded(char('#'), tuple((hex_byte, hex_byte, hex_byte)))
+        .map(|(red, green, blue)| Rgb(red, green, blue))
+        .context("#rrggbb colour")
+        .parse(input)
+}
+
+/// Comma separated `key=value` pairs, like `x=787,m=2655`.
+pub fn record<'a, K, V>(
+    key: impl Parser<Span<'a>, K, Error<'a>>,
+    value: impl Parser<Span<'a>, V, Error<'a>>,
+) -> impl FnMut(Span<'a>) -> IResult<'a, Vec<(K, V)>> {
+    comma_separated(separated_pair(key, char('='), value))
+}
+
+/// Runs `parser` over all of `input`, allowing trailing whitespace.
+pub fn finish<'a, T>(parser: impl Parser<Span<'a>, T, Error<'a>>, input: &'a str) -> Result<T, nom::Err<Error<'a>>> {
+    all_consuming(terminated(parser, multispace0))(Span::new(input)).map(|(_, parsed)| parsed)
+}
+
+/// The character of `input` where the parser got stuck and what it expected there.
+pub fn locate(input: &str, error: nom::Err<Error>) -> (SourceSpan, String) {
+    let (offset, message) = match error {
+        nom::Err::Error(tree) | nom::Err::Failure(tree) => furthest(&tree),
+        nom::Err::Incomplete(_) => (input.len(), None),
+    };
+    let message = message.unwrap_or_else(|| "unexpected input".to_string());
+    let length = input[offset..].chars().next().map_or(0, char::len_utf8);
+
+    ((offset, length).into(), message)
+}
+
+/// The error that got furthest into the input, which is usually the one explaining what's wrong.
+fn furthest(tree: &Error) -> (usize, Option<String>) {
+    match tree {
+        ErrorTree::Base { location, kind } => {
+            let message = match kind {
+                BaseErrorKind::Expected(expectation) => Some(format!("expected {}", expectation)),
+                BaseErrorKind::Kind(_) => None,
+                BaseErrorKind::External(error) => Some(error.to_string()),
+            };
+            (location.location_offset(), message)
+        }
+        ErrorTree::Stack { base, contexts } => {
+            let (offset, message) = furthest(base);
+            let message = match (message, contexts.first()) {
+                (Some(message), Some((_, StackContext::Context(context)))) => Some(format!("{} in {}", message, context)),
+                (None, Some((_, StackContext::Context(context)))) => Some(format!("expected {}", context)),
+                (message, _) => message,
+            };
+            (offset, message)
+        }
+        ErrorTree::Alt(alternatives) => alternatives.iter().map(furthest).max_by_key(|(offset, _)| *offset).unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+    use nom::character::complete::alpha1;
+    use nom::combinator::cut;
+    use nom_supreme::tag::complete::tag;
+
+    use super::*;
+
+    #[test]
+    fn numbers_and_labels() {
+        assert_eq!(Some(vec![79u64, 14, 55, 13]), finish(integers, "79 14  55 13\n").ok());
+        assert_eq!(Some(vec![-3i32, 7, 12]), finish(comma_separated(integer), "-3,7 , 12").ok());
+
+        let card = labeled(preceded(tag("Card").and(space1), integer::<u32>), integers::<u32>);
+        assert_eq!(Some((1, vec![41, 48])), finish(card, "Card 1: 41 48").ok());
+    }
+
+    #[test]
+    fn blocks_and_records() {
+        let input = "a=1,b=2\r\nc=3\r\n\r\n\r\nd=4\r\n\r\n";
+        let parsed = finish(blocks(lines(cut(record(alpha1.map(|key: Span| *key.fragment()), integer::<u8>)))), input);
+        assert_eq!(Some(vec![vec![vec![("a", 1), ("b", 2)], vec![("c", 3)]], vec![vec![("d", 4)]]]), parsed.ok());
+    }
+
+    #[test]
+    fn grid_and_color() {
+        let grid = finish(grid(one_of(".#").map(|c| c == '#')), "#..\n.#.\n").unwrap();
+        assert_eq!(IVec2::new(3, 2), grid.size());
+        assert!(grid[IVec2::new(1, 1)] && !grid[IVec2::new(2, 1)]);
+
+        assert_eq!(Some(Rgb(0x70, 0xc7, 0x10)), finish(hex_color, "#70c710").ok());
+    }
+
+    #[test]
+    fn errors() {
+        let input = "#..\n.#\n";
+        let (span, message) = locate(input, finish(grid(one_of(".#")), input).unwrap_err());
+        assert_eq!((0, "expected rectangular grid"), (span.offset(), message.as_str()));
+
+        let input = "1 2 x";
+        let (span, _) = locate(input, finish(integers::<u8>, input).unwrap_err());
+        assert_eq!(4, span.offset());
+    }
+}
@@ -0,0 +1,167 @@
+use enumset::{EnumSet, EnumSetType};
+use glam::IVec2;
+use pathfinding::num_traits::Zero;
+use pathfinding::prelude::astar;
+
+use crate::grid::Grid;
+use crate::Direction;
+
+/// A change of heading relative to the current one.
+#[derive(Debug, Hash, EnumSetType)]
+pub enum Turn {
+    Left,
+    Right,
+    Back,
+}
+
+/// How a walker may move over a grid: in straight runs of `min_run..=max_run` steps, changing heading only by
+/// one of `turns` between runs. The walker may set off in any direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Moves {
+    min_run: u32,
+    max_run: u32,
+    turns: EnumSet<Turn>,
+}
+
+/// The cheapest route found, from start to goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<C> {
+    pub cost: C,
+    pub positions: Vec<IVec2>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    position: IVec2,
+    heading: Option<Direction>,
+    run: u32,
+}
+
+impl Moves {
+    /// Runs of `min_run..=max_run` steps with left and right turns between them.
+    pub fn new(min_run: u32, max_run: u32) -> Self {
+        assert!(min_run <= max_run, "a run of at least {} steps can't be at most {} long", min_run, max_run);
+        Moves { min_run, max_run, turns: Turn::Left | Turn::Right }
+    }
+
+    pub fn with_turns(self, turns: EnumSet<Turn>) -> Self {
+        Moves { turns, ..self }
+    }
+
+    fn turn(from: Direction, to: Direction) -> Option<Turn> {
+        if to == from.turn_left() {
+            Some(Turn::Left)
+        } else if to == from.turn_right() {
+            Some(Turn::Right)
+        } else if to == from.opposite() {
+            Some(Turn::Back)
+        } else {
+            None
+        }
+    }
+
+    /// The run length after stepping towards `direction`, if that step is allowed.
+    fn step(&self, state: &State, direction: Direction) -> Option<u32> {
+        match state.heading {
+            None => Some(1),
+            Some(heading) if heading == direction => (state.run < self.max_run).then_some(state.run + 1),
+            Some(heading) => {
+                let turn = Moves::turn(heading, direction)?;
+                (state.run >= self.min_run && self.turns.contains(turn)).then_some(1)
+            }
+        }
+    }
+
+    /// Dijkstra from `start` to `goal`, where entering a cell costs what `cost` returns, or is impossible on `None`.
+    /// The goal only counts once the last run is at least `min_run` long.
+    pub fn shortest_path<T, C>(
+        &self,
+        grid: &Grid<T>,
+        start: IVec2,
+        goal: IVec2,
+        cost: impl Fn(IVec2, &T) -> Option<C>,
+    ) -> Option<Path<C>>
+    where
+        C: Zero + Ord + Copy,
+    {
+        self.shortest_path_guided(grid, start, goal, cost, |_| C::zero())
+    }
+
+    /// A* variant of [`Moves::shortest_path`]. `heuristic` must never overestimate the remaining cost from a position.
+    pub fn shortest_path_guided<T, C>(
+        &self,
+        grid: &Grid<T>,
+        start: IVec2,
+        goal: IVec2,
+        cost: impl Fn(IVec2, &T) -> Option<C>,
+        heuristic: impl Fn(IVec2) -> C,
+    ) -> Option<Path<C>>
+    where
+        C: Zero + Ord + Copy,
+    {
+        let (states, cost) = astar(
+            &State { position: start, heading: None, run: 0 },
+            |state| {
+                Direction::all()
+                    .filter_map(|direction| {
+                        let run = self.step(state, direction)?;
+                        let position = state.position + direction.delta();
+                        let cost = cost(position, grid.get(position)?)?;
+                        Some((State { position, heading: Some(direction), run }, cost))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |state| heuristic(state.position),
+            |state| state.position == goal && state.run >= self.min_run,
+        )?;
+
+        Some(Path { cost, positions: states.into_iter().map(|state| state.position).collect() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corridor() -> Grid<u32> {
+        Grid::new(5, 2, vec![1, 1, 1, 1, 1, 9, 9, 9, 9, 1])
+    }
+
+    #[test]
+    fn straight_runs() {
+        let grid = corridor();
+        let cost = |_, loss: &u32| Some(*loss);
+        let goal = IVec2::new(4, 1);
+
+        let free = Moves::new(1, 4).shortest_path(&grid, IVec2::ZERO, goal, cost).unwrap();
+        assert_eq!(5, free.cost);
+        assert_eq!(vec![IVec2::ZERO, IVec2::X, IVec2::new(2, 0), IVec2::new(3, 0), IVec2::new(4, 0), goal], free.positions);
+
+        // Three steps at most: one of the expensive cells has to be crossed.
+        let short = Moves::new(1, 3).shortest_path(&grid, IVec2::ZERO, goal, cost).unwrap();
+        assert_eq!(13, short.cost);
+
+        // The walker can't stop after a single step down.
+        assert_eq!(None, Moves::new(2, 4).shortest_path(&grid, IVec2::ZERO, goal, cost));
+    }
+
+    #[test]
+    fn turns() {
+        let grid = Grid::new(3, 1, vec![1, 1, 1]);
+        let cost = |_, loss: &u32| Some(*loss);
+
+        let path = Moves::new(2, 2).with_turns(Turn::Back.into()).shortest_path(&grid, IVec2::ZERO, IVec2::ZERO, cost);
+        assert_eq!(Some(4), path.map(|path| path.cost));
+        assert_eq!(None, Moves::new(2, 2).shortest_path(&grid, IVec2::ZERO, IVec2::ZERO, cost));
+    }
+
+    #[test]
+    fn walls() {
+        let grid = Grid::chars("..#\n#..\n");
+        let cost = |_, c: &char| (*c == '.').then_some(1i32);
+        let goal = IVec2::new(2, 1);
+        let manhattan = |p: IVec2| (goal.x - p.x).abs() + (goal.y - p.y).abs();
+        let path = Moves::new(1, 2).shortest_path_guided(&grid, IVec2::ZERO, goal, cost, manhattan);
+        assert_eq!(Some(3), path.map(|path| path.cost));
+    }
+}
@@ -0,0 +1,110 @@
+use glam::I64Vec2;
+
+use crate::math::gcd;
+use crate::Direction;
+
+/// A closed lattice polygon; the last vertex connects back to the first.
+///
+/// Areas are computed in `i128`, so coordinates in the hundreds of billions stay exact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<I64Vec2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<I64Vec2>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// Follows the moves starting at the origin. The final move may, but doesn't have to, return there.
+    pub fn from_moves(moves: impl IntoIterator<Item = (Direction, i64)>) -> Self {
+        let mut position = I64Vec2::ZERO;
+        let mut vertices = vec![position];
+        for (direction, distance) in moves {
+            position += I64Vec2::from(direction.delta()) * distance;
+            vertices.push(position);
+        }
+        if vertices.len() > 1 && vertices.last() == vertices.first() {
+            vertices.pop();
+        }
+
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[I64Vec2] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (I64Vec2, I64Vec2)> + '_ {
+        self.vertices.iter().copied().zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the signed area by the shoelace formula, which keeps it an integer. Positive if the
+    /// vertices run clockwise on screen, where `y` grows downwards.
+    pub fn twice_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+            .sum()
+    }
+
+    pub fn signed_area(&self) -> f64 {
+        self.twice_signed_area() as f64 / 2.0
+    }
+
+    /// Number of lattice points on the edges.
+    pub fn boundary(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| {
+                let delta = (b - a).abs();
+                gcd(delta.x as i128, delta.y as i128)
+            })
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside, by Pick's theorem: `A = I + B / 2 - 1`.
+    pub fn interior(&self) -> i128 {
+        (self.twice_signed_area().abs() - self.boundary() + 2) / 2
+    }
+
+    /// Number of lattice points inside or on the edges, i.e. the unit squares of a trench dug
+    /// along the edges plus everything it encloses.
+    pub fn dug_area(&self) -> i128 {
+        self.interior() + self.boundary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Direction::{Down, Left, Right, Up};
+
+    use super::*;
+
+    #[test]
+    fn square() {
+        let square = Polygon::from_moves([(Right, 2), (Down, 2), (Left, 2), (Up, 2)]);
+        assert_eq!(4, square.vertices().len());
+        assert_eq!(8, square.twice_signed_area());
+        assert_eq!(4.0, square.signed_area());
+        assert_eq!(8, square.boundary());
+        assert_eq!(1, square.interior());
+        assert_eq!(9, square.dug_area());
+
+        let reversed = Polygon::from_moves([(Down, 2), (Right, 2), (Up, 2), (Left, 2)]);
+        assert_eq!(-8, reversed.twice_signed_area());
+        assert_eq!(9, reversed.dug_area());
+    }
+
+    #[test]
+    fn diagonal_edges() {
+        let triangle = Polygon::new(vec![I64Vec2::new(0, 0), I64Vec2::new(4, 0), I64Vec2::new(0, 4)]);
+        assert_eq!(12, triangle.boundary());
+        assert_eq!(3, triangle.interior());
+    }
+
+    #[test]
+    fn huge() {
+        let distance = 900_000_000_000;
+        let square = Polygon::from_moves([(Right, distance), (Down, distance), (Left, distance), (Up, distance)]);
+        assert_eq!((distance as i128 + 1).pow(2), square.dug_area());
+    }
+}
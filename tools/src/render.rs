@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+use std::io::IsTerminal;
+
+use glam::IVec2;
+
+use crate::grid::Grid;
+
+pub use image::{Image, Rgb};
+
+mod image;
+
+/// Foreground colours for highlighting cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+}
+
+impl Color {
+    fn ansi(&self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::Gray => 90,
+        }
+    }
+}
+
+/// Whether the output may use colours and non-ASCII glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Fancy,
+    Plain,
+}
+
+impl Style {
+    /// Fancy on a terminal, plain when stdout is redirected.
+    pub fn detect() -> Self {
+        if std::io::stdout().is_terminal() { Style::Fancy } else { Style::Plain }
+    }
+}
+
+/// The closest ASCII character to a glyph: lines and corners of box drawing become `-`, `|` and `+`, blocks `#`.
+pub fn ascii(glyph: char) -> char {
+    match glyph {
+        c if c.is_ascii() => c,
+        '─' | '━' | '═' | '╌' | '┄' => '-',
+        '│' | '┃' | '║' | '╎' | '┆' => '|',
+        '\u{2500}'..='\u{257F}' => '+',
+        '·' | '░' => '.',
+        '\u{2580}'..='\u{259F}' => '#',
+        '←' => '<',
+        '→' => '>',
+        '↑' => '^',
+        '↓' => 'v',
+        _ => '?',
+    }
+}
+
+/// A drawn cell: its glyph and colour.
+pub type Styled = (char, Option<Color>);
+
+/// Writes already drawn cells as text, with ANSI colours unless `style` is plain.
+pub fn format(cells: &Grid<Styled>, style: Style) -> String {
+    let mut output = String::new();
+    for row in cells.rows() {
+        let mut current = None;
+        for (glyph, color) in row {
+            if style == Style::Plain {
+                output.push(ascii(*glyph));
+                continue;
+            }
+
+            if *color != current {
+                match color {
+                    Some(color) => output.push_str(&format!("\x1b[{}m", color.ansi())),
+                    None => output.push_str("\x1b[0m"),
+                }
+                current = *color;
+            }
+            output.push(*glyph);
+        }
+        if current.is_some() {
+            output.push_str("\x1b[0m");
+        }
+        output.push('\n');
+    }
+    output
+}
+
+type Glyph<'a, T> = Box<dyn Fn(IVec2, &T) -> char + 'a>;
+type Layer<'a> = (Color, Box<dyn Fn(IVec2) -> bool + 'a>);
+
+/// Draws a grid as text, one glyph per cell, with colour layers on top.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: Glyph<'a, T>,
+    layers: Vec<Layer<'a>>,
+    viewport: Option<(IVec2, IVec2)>,
+}
+
+impl<'a, T> Render<'a, T> {
+    pub fn new(grid: &'a Grid<T>, glyph: impl Fn(IVec2, &T) -> char + 'a) -> Self {
+        Render { grid, glyph: Box::new(glyph), layers: Vec::new(), viewport: None }
+    }
+
+    /// Colours the cells matching `selected`. Layers added later win.
+    pub fn layer(mut self, color: Color, selected: impl Fn(IVec2) -> bool + 'a) -> Self {
+        self.layers.push((color, Box::new(selected)));
+        self
+    }
+
+    /// Colours the given cells.
+    pub fn highlight(self, color: Color, positions: impl IntoIterator<Item = IVec2>) -> Self {
+        let positions: HashSet<IVec2> = positions.into_iter().collect();
+        self.layer(color, move |position| positions.contains(&position))
+    }
+
+    /// Only draws the `size` cells from `top_left` on, clipped to the grid.
+    pub fn viewport(mut self, top_left: IVec2, size: IVec2) -> Self {
+        self.viewport = Some((top_left, size));
+        self
+    }
+
+    fn bounds(&self) -> (IVec2, IVec2) {
+        let (top_left, size) = self.viewport.unwrap_or((IVec2::ZERO, self.grid.size()));
+        let top_left = top_left.clamp(IVec2::ZERO, self.grid.size());
+        let bottom_right = (top_left + size).clamp(top_left, self.grid.size());
+        (top_left, bottom_right)
+    }
+
+    /// The visible cells with their glyph and the colour of the topmost layer selecting them.
+    pub fn cells(&self) -> Grid<Styled> {
+        let (top_left, bottom_right) = self.bounds();
+        let size = bottom_right - top_left;
+        let mut cells = Vec::with_capacity((size.x * size.y) as usize);
+        for y in top_left.y..bottom_right.y {
+            for x in top_left.x..bottom_right.x {
+                let position = IVec2::new(x, y);
+                let glyph = (self.glyph)(position, &self.grid[position]);
+                let color = self.layers.iter().rev().find(|(_, selected)| selected(position)).map(|(color, _)| *color);
+                cells.push((glyph, color));
+            }
+        }
+        Grid::new(size.x as usize, size.y as usize, cells)
+    }
+
+    pub fn render(&self, style: Style) -> String {
+        format(&self.cells(), style)
+    }
+
+    /// Prints to stdout in the style the terminal supports.
+    pub fn print(&self) {
+        print!("{}", self.render(Style::detect()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::chars("┌─┐\n│.│\n└─┘\n")
+    }
+
+    #[test]
+    fn plain() {
+        let grid = sample();
+        let render = Render::new(&grid, |_, c| *c).highlight(Color::Red, [IVec2::ZERO]);
+        assert_eq!("+-+\n|.|\n+-+\n", render.render(Style::Plain));
+        assert_eq!(".|\n-+\n", render.viewport(IVec2::ONE, IVec2::new(5, 5)).render(Style::Plain));
+    }
+
+    #[test]
+    fn colors() {
+        let grid = sample();
+        let render = Render::new(&grid, |_, c| *c)
+            .layer(Color::Gray, |position| position.y == 1)
+            .highlight(Color::Red, [IVec2::new(1, 1)]);
+        let rendered = render.render(Style::Fancy);
+        assert_eq!("┌─┐", rendered.lines().next().unwrap());
+        assert_eq!("\x1b[90m│\x1b[31m.\x1b[90m│\x1b[0m", rendered.lines().nth(1).unwrap());
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use glam::{DVec2, I64Vec2, IVec2};
+
+use crate::grid::Grid;
+use crate::polygon::Polygon;
+use crate::render::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    /// Halfway between both colours, like a fill with 50% opacity.
+    fn mix(&self, other: Rgb) -> Rgb {
+        let mix = |a: u8, b: u8| ((a as u16 + b as u16) / 2) as u8;
+        Rgb(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Red => Rgb(220, 50, 47),
+            Color::Green => Rgb(133, 153, 0),
+            Color::Yellow => Rgb(181, 137, 0),
+            Color::Blue => Rgb(38, 139, 210),
+            Color::Magenta => Rgb(211, 54, 130),
+            Color::Cyan => Rgb(42, 161, 152),
+            Color::Gray => Rgb(88, 110, 117),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Shape {
+    points: Vec<DVec2>,
+    stroke: Rgb,
+    fill: Option<Rgb>,
+}
+
+/// A picture of unit cells and closed outlines on a `width` × `height` canvas, exported as SVG or binary PPM.
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: f64,
+    height: f64,
+    background: Rgb,
+    cells: Vec<(IVec2, Rgb)>,
+    shapes: Vec<Shape>,
+}
+
+impl Image {
+    pub fn new(width: f64, height: f64, background: Rgb) -> Self {
+        Image { width, height, background, cells: Vec::new(), shapes: Vec::new() }
+    }
+
+    /// One cell per grid cell, coloured by `fill`; `None` leaves the background.
+    pub fn from_grid<T>(grid: &Grid<T>, background: Rgb, fill: impl Fn(IVec2, &T) -> Option<Rgb>) -> Self {
+        let mut image = Image::new(grid.width() as f64, grid.height() as f64, background);
+        image.cells = grid.iter().filter_map(|(position, cell)| Some((position, fill(position, cell)?))).collect();
+        image
+    }
+
+    /// The polygon moved into view, with its bounding box as the canvas.
+    pub fn from_polygon(polygon: &Polygon, background: Rgb, stroke: Rgb, fill: Option<Rgb>) -> Self {
+        let vertices = polygon.vertices();
+        let min = vertices.iter().copied().reduce(I64Vec2::min).unwrap_or_default();
+        let max = vertices.iter().copied().reduce(I64Vec2::max).unwrap_or_default();
+        let size = (max - min).as_dvec2();
+        let mut image = Image::new(size.x.max(1.0), size.y.max(1.0), background);
+        image.shape(vertices.iter().map(|vertex| (*vertex - min).as_dvec2()), stroke, fill);
+        image
+    }
+
+    pub fn cell(&mut self, position: IVec2, color: Rgb) {
+        self.cells.push((position, color));
+    }
+
+    /// A closed outline through `points`, drawn over the cells.
+    pub fn shape(&mut self, points: impl IntoIterator<Item = DVec2>, stroke: Rgb, fill: Option<Rgb>) {
+        self.shapes.push(Shape { points: points.into_iter().collect(), stroke, fill });
+    }
+
+    pub fn to_svg(&self) -> String {
+        let scale = 1024.0 / self.width.max(self.height);
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {w} {h}" width="{}" height="{}" shape-rendering="crispEdges">"#,
+            (self.width * scale).ceil(),
+            (self.height * scale).ceil(),
+            w = self.width,
+            h = self.height,
+        ).unwrap();
+        writeln!(svg, r#"<rect width="{}" height="{}" fill="{}"/>"#, self.width, self.height, self.background.hex()).unwrap();
+        for (position, color) in self.cells.iter() {
+            writeln!(svg, r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#, position.x, position.y, color.hex()).unwrap();
+        }
+        for shape in self.shapes.iter() {
+            let points: Vec<String> = shape.points.iter().map(|point| format!("{},{}", point.x, point.y)).collect();
+            writeln!(
+                svg,
+                r#"<polygon points="{}" fill="{}" fill-opacity="0.5" stroke="{}" stroke-width="{}"/>"#,
+                points.join(" "),
+                shape.fill.map_or("none".to_string(), |fill| fill.hex()),
+                shape.stroke.hex(),
+                2.0 / scale,
+            ).unwrap();
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// A binary PPM (P6) whose longer side has `size` pixels.
+    pub fn to_ppm(&self, size: u32) -> Vec<u8> {
+        let scale = size as f64 / self.width.max(self.height);
+        let (width, height) = ((self.width * scale).ceil().max(1.0) as usize, (self.height * scale).ceil().max(1.0) as usize);
+        let mut pixels = vec![self.background; width * height];
+        let mut paint = |x: i64, y: i64, color: Rgb, blend: bool| {
+            if (0..width as i64).contains(&x) && (0..height as i64).contains(&y) {
+                let pixel = &mut pixels[y as usize * width + x as usize];
+                *pixel = if blend { pixel.mix(color) } else { color };
+            }
+        };
+
+        for (position, color) in self.cells.iter() {
+            let from = (position.as_dvec2() * scale).floor().as_i64vec2();
+            let to = ((position.as_dvec2() + 1.0) * scale).floor().as_i64vec2().max(from + 1);
+            for y in from.y..to.y {
+                for x in from.x..to.x {
+                    paint(x, y, *color, false);
+                }
+            }
+        }
+
+        for shape in self.shapes.iter() {
+            let points: Vec<DVec2> = shape.points.iter().map(|point| *point * scale).collect();
+            let edges = || points.iter().zip(points.iter().cycle().skip(1));
+            if let Some(fill) = shape.fill {
+                // Even-odd rule along each row of pixel centres.
+                for y in 0..height {
+                    let centre = y as f64 + 0.5;
+                    let mut crossings: Vec<f64> = edges()
+                        .filter(|(a, b)| (a.y <= centre) != (b.y <= centre))
+                        .map(|(a, b)| a.x + (centre - a.y) / (b.y - a.y) * (b.x - a.x))
+                        .collect();
+                    crossings.sort_by(f64::total_cmp);
+                    for span in crossings.chunks_exact(2) {
+                        for x in (span[0] - 0.5).ceil() as i64..=(span[1] - 0.5).floor() as i64 {
+                            paint(x, y as i64, fill, true);
+                        }
+                    }
+                }
+            }
+            for (a, b) in edges() {
+                let steps = (*b - *a).abs().max_element().ceil().max(1.0) as usize;
+                for step in 0..=steps {
+                    let point = a.lerp(*b, step as f64 / steps as f64);
+                    paint(point.x as i64, point.y as i64, shape.stroke, false);
+                }
+            }
+        }
+
+        let mut ppm = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        ppm.extend(pixels.iter().flat_map(|pixel| [pixel.0, pixel.1, pixel.2]));
+        ppm
+    }
+
+    /// Writes `out/dayNN.svg` and returns its path.
+    pub fn save_svg(&self, day: u8) -> io::Result<PathBuf> {
+        let path = PathBuf::from("out").join(format!("day{:02}.svg", day));
+        fs::create_dir_all("out")?;
+        fs::write(&path, self.to_svg())?;
+        Ok(path)
+    }
+
+    /// Writes `out/dayNN.ppm` and returns its path.
+    pub fn save_ppm(&self, day: u8) -> io::Result<PathBuf> {
+        let path = PathBuf::from("out").join(format!("day{:02}.ppm", day));
+        fs::create_dir_all("out")?;
+        fs::write(&path, self.to_ppm(1024))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+
+    #[test]
+    fn grid() {
+        let grid = Grid::chars("#.\n.#\n");
+        let image = Image::from_grid(&grid, Rgb::WHITE, |_, c| (*c == '#').then_some(Rgb::BLACK));
+        let svg = image.to_svg();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 2 2""#));
+        assert_eq!(2, svg.matches(r##"width="1" height="1" fill="#000000""##).count());
+
+        let ppm = image.to_ppm(4);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(header, &ppm[..header.len()]);
+        let pixels = &ppm[header.len()..];
+        assert_eq!(4 * 4 * 3, pixels.len());
+        assert_eq!([0, 0, 0], pixels[..3]);
+        assert_eq!([255, 255, 255], pixels[2 * 3..3 * 3]);
+        assert_eq!([0, 0, 0], pixels[(3 * 4 + 3) * 3..]);
+    }
+
+    #[test]
+    fn polygon() {
+        let square = Polygon::from_moves([(Direction::Right, 4), (Direction::Down, 4), (Direction::Left, 4), (Direction::Up, 4)]);
+        let image = Image::from_polygon(&square, Rgb::WHITE, Rgb::BLACK, Some(Rgb(255, 0, 0)));
+        assert!(image.to_svg().contains(r#"<polygon points="0,0 4,0 4,4 0,4""#));
+
+        let ppm = image.to_ppm(8);
+        let pixels = &ppm[b"P6\n8 8\n255\n".len()..];
+        let pixel = |x: usize, y: usize| &pixels[(y * 8 + x) * 3..(y * 8 + x + 1) * 3];
+        assert_eq!([0, 0, 0], pixel(0, 0));
+        assert_eq!([255, 127, 127], pixel(4, 4));
+    }
+}
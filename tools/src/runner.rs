@@ -0,0 +1,93 @@
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use crate::render::Image;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Timed<T> {
+    pub value: T,
+    pub elapsed: Duration,
+}
+
+impl<T> Timed<T> {
+    fn measure<E>(f: impl FnOnce() -> Result<T, E>) -> Result<Timed<T>, E> {
+        let start = Instant::now();
+        let value = f()?;
+        Ok(Timed { value, elapsed: start.elapsed() })
+    }
+}
+
+/// Answers of one run over one input, with how long each phase took.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub parse: Duration,
+    pub answers: Vec<(Part, Timed<String>)>,
+}
+
+impl Outcome {
+    pub fn answer(&self, part: Part) -> Option<&Timed<String>> {
+        self.answers.iter().find(|(p, _)| *p == part).map(|(_, answer)| answer)
+    }
+}
+
+/// Object safe face of a [`Solution`], so days with different `Parsed`/answer types fit in one list.
+pub trait Runnable: Sync {
+    fn day(&self) -> u8;
+    fn run(&self, content: &str, parts: &[Part]) -> miette::Result<Outcome>;
+    fn image(&self, content: &str) -> miette::Result<Option<Image>>;
+}
+
+pub struct Runner<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution + 'static> Runner<S> {
+    pub fn boxed() -> Box<dyn Runnable> {
+        Box::new(Runner::<S>(PhantomData))
+    }
+}
+
+impl<S: Solution> Runnable for Runner<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, content: &str, parts: &[Part]) -> miette::Result<Outcome> {
+        let root = Timed::measure(|| S::parse(content))?;
+        let mut answers = Vec::new();
+        for part in parts {
+            let answer = match part {
+                Part::One => Timed::measure(|| S::part1(&root.value).map(|a| a.to_string()))?,
+                Part::Two => Timed::measure(|| S::part2(&root.value).map(|a| a.to_string()))?,
+            };
+            answers.push((*part, answer));
+        }
+
+        Ok(Outcome { parse: root.elapsed, answers })
+    }
+
+    fn image(&self, content: &str) -> miette::Result<Option<Image>> {
+        Ok(S::image(&S::parse(content)?))
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use miette::Diagnostic;
+
+use crate::render::Image;
+
+/// A single day of the calendar: how to parse its input and how to answer both parts.
+pub trait Solution {
+    /// Day of December the puzzle belongs to.
+    const DAY: u8;
+
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+    type Error: Diagnostic + From<std::io::Error> + Send + Sync + 'static;
+
+    fn parse(content: &str) -> Result<Self::Parsed, Self::Error>;
+    fn part1(root: &Self::Parsed) -> Result<Self::Answer1, Self::Error>;
+    fn part2(root: &Self::Parsed) -> Result<Self::Answer2, Self::Error>;
+
+    /// A picture of the puzzle for `aoc run --svg`, if the day draws one.
+    fn image(_root: &Self::Parsed) -> Option<Image> {
+        None
+    }
+}
+
+/// Parses every file and prints the answers of both parts, like the old per-day `main`s did.
+pub fn run<S: Solution>(files: &[&str]) -> Result<(), S::Error> {
+    for file in files {
+        println!("Reading {}", file);
+        let content = crate::input::load(file)?;
+        let root = S::parse(&content)?;
+        println!("Part 1: {}", S::part1(&root)?);
+        println!("Part 2: {}", S::part2(&root)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Plain text tables, for reports printed to a terminal.
+
+/// The rows with their columns padded to the same width and separated by ` | `, one row per line.
+/// The first row is usually the header. Trailing spaces are trimmed.
+pub fn aligned<R: AsRef<[String]>>(rows: &[R]) -> String {
+    let columns = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| rows.iter().filter_map(|row| row.as_ref().get(i)).map(|cell| cell.chars().count()).max().unwrap())
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligned_columns() {
+        let rows = [["Day", "Part", "Answer"], ["1", "2", "fünf"], ["12", "1", ""]].map(|row| row.map(String::from));
+        assert_eq!("Day | Part | Answer\n1   | 2    | fünf\n12  | 1    |", aligned(&rows));
+        assert_eq!("", aligned::<Vec<String>>(&[]));
+    }
+}